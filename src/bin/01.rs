@@ -1,14 +1,13 @@
+use advent_of_code::util::parse::{integers, relocate};
+
 advent_of_code::solution!(1);
 
-fn parse(input: &str) -> (Vec<i32>, Vec<i32>) {
+fn parse(input: &str) -> miette::Result<(Vec<i32>, Vec<i32>)> {
     let mut left: Vec<i32> = Vec::new();
     let mut right: Vec<i32> = Vec::new();
 
     for line in input.lines() {
-        let result: Vec<i32> = line
-            .split_whitespace()
-            .filter_map(|digit| digit.parse::<i32>().ok())
-            .collect();
+        let result: Vec<i32> = integers(line).map_err(|e| relocate(e, input, line))?;
 
         if result.len() == 2 {
             left.push(result[0]);
@@ -16,11 +15,11 @@ fn parse(input: &str) -> (Vec<i32>, Vec<i32>) {
         }
     }
 
-    (left, right)
+    Ok((left, right))
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
-    let (mut left, mut right) = parse(input);
+pub fn part_one(input: &str) -> miette::Result<Option<u32>> {
+    let (mut left, mut right) = parse(input)?;
 
     left.sort();
     right.sort();
@@ -31,11 +30,11 @@ pub fn part_one(input: &str) -> Option<u32> {
         .map(|(l, r)| (l - r).abs())
         .sum();
 
-    Some(result as u32)
+    Ok(Some(result as u32))
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    let (mut left, mut right) = parse(input);
+pub fn part_two(input: &str) -> miette::Result<Option<u32>> {
+    let (mut left, mut right) = parse(input)?;
 
     left.sort();
     right.sort();
@@ -53,7 +52,7 @@ pub fn part_two(input: &str) -> Option<u32> {
         .map(|(l, r)| *l * (*r as i32))
         .sum::<i32>();
 
-    Some(result as u32)
+    Ok(Some(result as u32))
 }
 
 #[cfg(test)]
@@ -63,12 +62,18 @@ mod tests {
     #[test]
     fn test_part_one() {
         let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(11));
+        assert_eq!(result.unwrap(), Some(11));
     }
 
     #[test]
     fn test_part_two() {
         let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(31));
+        assert_eq!(result.unwrap(), Some(31));
+    }
+
+    #[test]
+    fn test_parse_error_points_at_number() {
+        let error = part_one("3   4\n4   99999999999\n").unwrap_err();
+        assert_eq!(error.labels().unwrap().next().unwrap().offset(), 10);
     }
 }
@@ -1,5 +1,8 @@
 use std::str::FromStr;
 
+use advent_of_code::util::parse::{integers, parse_lines};
+use miette::Report;
+
 advent_of_code::solution!(2);
 
 #[derive(Debug, Clone)]
@@ -8,13 +11,10 @@ struct Line {
 }
 
 impl FromStr for Line {
-    type Err = ();
+    type Err = Report;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let reports = s
-            .split_whitespace()
-            .map(|s| s.parse().unwrap())
-            .collect::<Vec<i32>>();
+        let reports = integers(s)?;
 
         Ok(Self { reports })
     }
@@ -49,21 +49,21 @@ fn remove_at(line: &Line, index: usize) -> Line {
     Line { reports: new_line }
 }
 
-fn parse_input(input: &str) -> Vec<Line> {
-    input.lines().map(|line| line.parse().unwrap()).collect()
+fn parse_input(input: &str) -> miette::Result<Vec<Line>> {
+    parse_lines(input, str::parse)
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
-    let lines = parse_input(input);
+pub fn part_one(input: &str) -> miette::Result<Option<u32>> {
+    let lines = parse_input(input)?;
     let result = lines.iter().filter(|line| is_safe(line, false)).count();
 
-    Some(result as u32)
+    Ok(Some(result as u32))
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    let lines = parse_input(input);
+pub fn part_two(input: &str) -> miette::Result<Option<u32>> {
+    let lines = parse_input(input)?;
     let result = lines.iter().filter(|line| is_safe(line, true)).count();
-    Some(result as u32)
+    Ok(Some(result as u32))
 }
 
 #[cfg(test)]
@@ -73,12 +73,18 @@ mod tests {
     #[test]
     fn test_part_one() {
         let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(2));
+        assert_eq!(result.unwrap(), Some(2));
     }
 
     #[test]
     fn test_part_two() {
         let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(4));
+        assert_eq!(result.unwrap(), Some(4));
+    }
+
+    #[test]
+    fn test_parse_error_points_at_number() {
+        let error = part_one("1 2 3\n4 99999999999 5\n").unwrap_err();
+        assert_eq!(error.labels().unwrap().next().unwrap().offset(), 8);
     }
 }
@@ -1,7 +1,8 @@
 #![allow(dead_code)]
 use std::str::FromStr;
 
-use miette::Error;
+use advent_of_code::util::parse::{error_in, parse_lines, relocate, sections};
+use miette::{miette, Error, Report};
 
 advent_of_code::solution!(5);
 
@@ -9,10 +10,12 @@ advent_of_code::solution!(5);
 struct PageNumber(u32);
 
 impl FromStr for PageNumber {
-    type Err = ();
+    type Err = Report;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        s.parse().map(PageNumber).map_err(|_| ())
+        s.parse()
+            .map(PageNumber)
+            .map_err(|_| error_in(s, s, "expected a page number"))
     }
 }
 
@@ -23,13 +26,15 @@ struct Rule {
 }
 
 impl FromStr for Rule {
-    type Err = ();
+    type Err = Report;
 
     // Expected input: 47|53
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (before, after) = s.split_once('|').ok_or(())?;
-        let before = before.parse().map_err(|_| ())?;
-        let after = after.parse().map_err(|_| ())?;
+        let (before, after) = s
+            .split_once('|')
+            .ok_or_else(|| error_in(s, s, "expected a rule like `47|53`"))?;
+        let before = before.parse().map_err(|e| relocate(e, s, before))?;
+        let after = after.parse().map_err(|e| relocate(e, s, after))?;
         Ok(Rule { before, after })
     }
 }
@@ -41,13 +46,13 @@ struct Update {
 }
 
 impl FromStr for Update {
-    type Err = ();
+    type Err = Report;
 
-    // Expected input: 47|53
+    // Expected input: 75,47,61,53,29
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let pages = s
             .split(',')
-            .map(|page| page.parse().map_err(|_| ()))
+            .map(|page| page.parse().map_err(|e| relocate(e, s, page)))
             .collect::<Result<Vec<_>, _>>()?;
         Ok(Update { pages })
     }
@@ -119,27 +124,18 @@ impl Update {
     }
 }
 
-fn parse_input(input: &str) -> Result<(Vec<Rule>, Vec<Update>), ()> {
-    let mut sections = input.split("\n\n");
-    let rules = sections
-        .next()
-        .ok_or(())?
-        .lines()
-        .map(|line| line.parse())
-        .collect::<Result<Vec<_>, _>>()?;
-
-    let updates: Vec<Update> = sections
-        .next()
-        .ok_or(())?
-        .lines()
-        .map(|line| line.parse())
-        .collect::<Result<Vec<_>, _>>()?;
+fn parse_input(input: &str) -> miette::Result<(Vec<Rule>, Vec<Update>)> {
+    let [rules, updates] = sections(input)
+        .try_into()
+        .map_err(|_| miette!("expected rules and updates separated by a blank line"))?;
+    let rules = parse_lines(rules, str::parse).map_err(|e| relocate(e, input, rules))?;
+    let updates = parse_lines(updates, str::parse).map_err(|e| relocate(e, input, updates))?;
 
     Ok((rules, updates))
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
-    let (rules, updates) = parse_input(input).ok()?;
+pub fn part_one(input: &str) -> miette::Result<Option<u32>> {
+    let (rules, updates) = parse_input(input)?;
 
     let valid_updates: Vec<&Update> = updates
         .iter()
@@ -153,11 +149,11 @@ pub fn part_one(input: &str) -> Option<u32> {
         .map(|page| page.0)
         .sum();
 
-    Some(result)
+    Ok(Some(result))
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    let (rules, updates) = parse_input(input).ok()?;
+pub fn part_two(input: &str) -> miette::Result<Option<u32>> {
+    let (rules, updates) = parse_input(input)?;
 
     let result: u32 = updates
         .into_iter()
@@ -171,7 +167,7 @@ pub fn part_two(input: &str) -> Option<u32> {
         })
         .sum();
 
-    Some(result)
+    Ok(Some(result))
 }
 
 #[cfg(test)]
@@ -181,12 +177,20 @@ mod tests {
     #[test]
     fn test_part_one() {
         let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(143));
+        assert_eq!(result.unwrap(), Some(143));
     }
 
     #[test]
     fn test_part_two() {
         let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(123));
+        assert_eq!(result.unwrap(), Some(123));
+    }
+
+    #[test]
+    fn test_parse_error_points_at_page() {
+        let input = "47|53\n97|1x\n\n75,47";
+        let error = part_one(input).unwrap_err();
+        let label = error.labels().unwrap().next().unwrap();
+        assert_eq!((label.offset(), label.len()), (9, 2));
     }
 }
@@ -2,71 +2,53 @@
 
 use std::{fmt::Debug, str::FromStr};
 
-use miette::Error;
-advent_of_code::solution!(6);
+#[cfg(test)]
+use advent_of_code::util::image::Image;
+use advent_of_code::util::{grid::Grid, point::*, rng::Rng};
+use miette::{miette, Error, Report};
+advent_of_code::solution!(6, generator = generate);
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
 enum Position {
     Blocked,
     Empty,
     Guard,
 }
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
-enum Direction {
-    North,
-    East,
-    South,
-    West,
-}
-
 // # = Blocked, . = Empty
-impl FromStr for Position {
-    type Err = String;
+impl TryFrom<u8> for Position {
+    type Error = String;
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "#" => Ok(Position::Blocked),
-            "." => Ok(Position::Empty),
-            "^" => Ok(Position::Guard),
-            _ => Err(format!("Invalid position: {}", s)),
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            b'#' => Ok(Position::Blocked),
+            b'.' => Ok(Position::Empty),
+            b'^' => Ok(Position::Guard),
+            _ => Err(format!("Invalid position: {}", value as char)),
         }
     }
 }
 
 #[derive(Clone)]
 struct Map {
-    positions: Vec<Vec<Position>>,
-    visited_positions: Vec<Vec<bool>>,
-    guard_position: (usize, usize),
+    positions: Grid<Position>,
+    visited_positions: Grid<bool>,
+    guard_position: Point,
     guard_direction: Direction,
 }
 
 impl FromStr for Map {
-    type Err = String;
+    type Err = Report;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut guard_position = (0, 0);
-        let positions: Vec<Vec<Position>> = s
-            .lines()
-            .enumerate()
-            .map(|(y, line)| {
-                line.chars()
-                    .enumerate()
-                    .map(|(x, c)| {
-                        if c == '^' {
-                            guard_position = (x, y);
-                            Position::Empty
-                        } else {
-                            c.to_string().parse::<Position>().unwrap()
-                        }
-                    })
-                    .collect()
-            })
-            .collect();
+        let mut positions = Grid::parse_with(s, Position::try_from).map_err(|e| e.report(s))?;
+        let guard_position = positions
+            .find(Position::Guard)
+            .ok_or_else(|| miette!("Map does not contain a guard"))?;
+        positions[guard_position] = Position::Empty;
 
         Ok(Map {
-            visited_positions: vec![vec![false; positions[0].len()]; positions.len()],
+            visited_positions: positions.same_size_with(false),
             positions,
             guard_position,
             guard_direction: Direction::North,
@@ -77,21 +59,21 @@ impl FromStr for Map {
 impl Debug for Map {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         writeln!(f)?;
-        for (y, row) in self.positions.iter().enumerate() {
-            for (x, pos) in row.iter().enumerate() {
-                if (x, y) == self.guard_position {
-                    write!(f, "^")?;
-                    continue;
-                }
-                match pos {
-                    Position::Blocked => write!(f, "#")?,
-                    Position::Empty => write!(f, ".")?,
-                    Position::Guard => write!(f, "^")?,
-                }
-            }
-            writeln!(f)?;
-        }
-
+        self.positions
+            .renderer(|pos| match pos {
+                Position::Blocked => '#',
+                Position::Empty => '.',
+                Position::Guard => '^',
+            })
+            .mark(
+                self.visited_positions
+                    .iter()
+                    .filter(|&(_, &visited)| visited)
+                    .map(|(point, _)| point),
+                'X',
+            )
+            .cursor(self.guard_position, self.guard_direction.arrow())
+            .write_to(f)?;
         writeln!(f)?;
 
         writeln!(f, "Guard position: {:?}", self.guard_position)?;
@@ -101,82 +83,64 @@ impl Debug for Map {
 }
 
 impl Map {
-    fn tick(&mut self) -> Result<(), Error> {
-        Ok(())
+    /// Marks the guard's position as visited and moves or turns the guard once.
+    /// Returns `false` once the guard walks off the map.
+    fn tick(&mut self) -> bool {
+        self.visited_positions[self.guard_position] = true;
+        match self.position_in_front_of_guard() {
+            None => return false,
+            Some(Position::Blocked) => {
+                self.turn_right();
+            }
+            Some(Position::Empty) => {
+                self.guard_position += self.guard_direction.to_point();
+            }
+            Some(Position::Guard) => {
+                panic!("Guard should never be in front of guard");
+            }
+        }
+        true
     }
 
     fn turn_right(&mut self) {
-        self.guard_direction = match self.guard_direction {
-            Direction::North => Direction::East,
-            Direction::East => Direction::South,
-            Direction::South => Direction::West,
-            Direction::West => Direction::North,
-        }
+        self.guard_direction = self.guard_direction.clockwise();
     }
 
     fn simulate_plain(&mut self) -> Result<(), Error> {
-        loop {
-            self.visited_positions[self.guard_position.1][self.guard_position.0] = true;
-            match self.position_in_front_of_guard() {
-                None => break,
-                Some(Position::Blocked) => {
-                    self.turn_right();
-                }
-                Some(Position::Empty) => {
-                    self.guard_position = match self.guard_direction {
-                        Direction::North => (self.guard_position.0, self.guard_position.1 - 1),
-                        Direction::East => (self.guard_position.0 + 1, self.guard_position.1),
-                        Direction::South => (self.guard_position.0, self.guard_position.1 + 1),
-                        Direction::West => (self.guard_position.0 - 1, self.guard_position.1),
-                    };
-                }
-                Some(Position::Guard) => {
-                    panic!("Guard should never be in front of guard");
-                }
-            }
-        }
+        while self.tick() {}
         Ok(())
     }
 
-    fn position_in_front_of_guard(&self) -> Option<Position> {
-        let (x, y) = self.guard_position;
-        match self.guard_direction {
-            Direction::North => {
-                if y == 0 {
-                    None
-                } else {
-                    Some(self.positions[y - 1][x])
-                }
-            }
-            Direction::East => {
-                if x == self.positions[0].len() - 1 {
-                    None
-                } else {
-                    Some(self.positions[y][x + 1])
-                }
-            }
-            Direction::South => {
-                if y == self.positions.len() - 1 {
-                    None
-                } else {
-                    Some(self.positions[y + 1][x])
-                }
-            }
-            Direction::West => {
-                if x == 0 {
-                    None
-                } else {
-                    Some(self.positions[y][x - 1])
-                }
+    /// Renders obstructions in white, visited positions in blue and the guard in red.
+    #[cfg(test)]
+    fn image(&self) -> Image {
+        let mut image = Image::from_grid(&self.positions, |pos| match pos {
+            Position::Blocked => [255, 255, 255],
+            Position::Empty | Position::Guard => [0, 0, 0],
+        });
+        for (point, &visited) in self.visited_positions.iter() {
+            if visited {
+                image.set_pixel(point.x as usize, point.y as usize, [40, 80, 200]);
             }
         }
+        image.set_pixel(
+            self.guard_position.x as usize,
+            self.guard_position.y as usize,
+            [220, 40, 40],
+        );
+        image
+    }
+
+    fn position_in_front_of_guard(&self) -> Option<Position> {
+        self.positions
+            .get(self.guard_position + self.guard_direction.to_point())
+            .copied()
     }
 
     fn count_visited_positions(&self) -> usize {
         self.visited_positions
             .iter()
-            .flatten()
-            .filter(|&&visited| visited)
+            .filter(|(_, &visited)| visited)
             .count()
     }
 
@@ -184,7 +148,7 @@ impl Map {
     // If we end up in a square we've been to before, then return true
     // If we end up going off the map, then false
     fn will_loop(&mut self) -> bool {
-        let mut seen: Vec<((usize, usize), Direction)> = Vec::new();
+        let mut seen: Vec<(Point, Direction)> = Vec::new();
         loop {
             // check if we've been here before
             if seen.contains(&(self.guard_position, self.guard_direction)) {
@@ -197,12 +161,7 @@ impl Map {
                     self.turn_right();
                 }
                 Some(Position::Empty) => {
-                    self.guard_position = match self.guard_direction {
-                        Direction::North => (self.guard_position.0, self.guard_position.1 - 1),
-                        Direction::East => (self.guard_position.0 + 1, self.guard_position.1),
-                        Direction::South => (self.guard_position.0, self.guard_position.1 + 1),
-                        Direction::West => (self.guard_position.0 - 1, self.guard_position.1),
-                    };
+                    self.guard_position += self.guard_direction.to_point();
                 }
                 Some(Position::Guard) => {
                     panic!("Guard should never be in front of guard");
@@ -214,30 +173,29 @@ impl Map {
     }
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
-    let mut map = input.parse::<Map>().unwrap();
-    map.simulate_plain().unwrap();
-    Some(map.count_visited_positions() as u32)
+pub fn part_one(input: &str) -> miette::Result<Option<u32>> {
+    let mut map = input.parse::<Map>()?;
+    map.simulate_plain()?;
+    Ok(Some(map.count_visited_positions() as u32))
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    let mut map = input.parse::<Map>().unwrap();
-    map.simulate_plain().unwrap();
+pub fn part_two(input: &str) -> miette::Result<Option<u32>> {
+    let map = input.parse::<Map>()?;
+    let mut walked = map.clone();
+    walked.simulate_plain()?;
 
     let mut looping_count = 0;
 
-    let mut maps_to_test: Vec<Map> = Vec::with_capacity(map.count_visited_positions());
-
-    for (j, line) in map.visited_positions.iter().enumerate() {
-        for (i, pos) in line.iter().enumerate() {
-            if !pos {
-                continue;
-            }
+    let mut maps_to_test: Vec<Map> = Vec::with_capacity(walked.count_visited_positions());
 
-            let mut test_map = input.parse::<Map>().unwrap();
-            test_map.positions[j][i] = Position::Blocked;
-            maps_to_test.push(test_map);
+    for (point, &visited) in walked.visited_positions.iter() {
+        if !visited {
+            continue;
         }
+
+        let mut test_map = map.clone();
+        test_map.positions[point] = Position::Blocked;
+        maps_to_test.push(test_map);
     }
 
     for mut test_map in maps_to_test {
@@ -246,22 +204,79 @@ pub fn part_two(input: &str) -> Option<u32> {
         }
     }
 
-    Some(looping_count as u32)
+    Ok(Some(looping_count as u32))
+}
+
+/// Generates a square lab with scattered obstructions and a guard facing north.
+/// Labs in which the guard would walk in a loop are discarded, as the guard has to leave the lab.
+pub fn generate(size: usize, rng: &mut Rng) -> String {
+    let size = size.max(1);
+
+    loop {
+        let guard = rng.index(size * size);
+
+        let mut input = String::with_capacity(size * (size + 1));
+        for i in 0..size * size {
+            input.push(match i {
+                i if i == guard => '^',
+                _ if rng.chance(0.1) => '#',
+                _ => '.',
+            });
+            if i % size == size - 1 {
+                input.push('\n');
+            }
+        }
+
+        if !input.parse::<Map>().unwrap().will_loop() {
+            return input;
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use advent_of_code::template::generator::{assert_generator, is_square_grid};
+    use advent_of_code::util::image::FrameRecorder;
 
     #[test]
     fn test_part_one() {
         let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(41));
+        assert_eq!(result.unwrap(), Some(41));
     }
 
     #[test]
     fn test_part_two() {
         let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(6));
+        assert_eq!(result.unwrap(), Some(6));
+    }
+
+    #[test]
+    fn test_record_guard_walk() {
+        let mut map = advent_of_code::template::read_file("examples", DAY)
+            .parse::<Map>()
+            .unwrap();
+        let mut recorder = FrameRecorder::new().scale(4);
+        recorder.record(map.image());
+        let mut ticks = 0;
+        while map.tick() {
+            recorder.record(map.image());
+            ticks += 1;
+        }
+
+        let mut gif = Vec::new();
+        recorder.write_gif(&mut gif).unwrap();
+        assert!(gif.starts_with(b"GIF89a"));
+        assert_eq!(recorder.len(), ticks + 1);
+        assert_eq!(map.count_visited_positions(), 41);
+    }
+
+    #[test]
+    fn test_generate() {
+        assert_generator(generate, |input| {
+            is_square_grid(input, |c| ".#^".contains(c))
+                && input.matches('^').count() == 1
+                && !input.parse::<Map>().unwrap().will_loop()
+        });
     }
 }
@@ -1,9 +1,8 @@
-use nom::character::complete::{char, digit1, space1};
-use nom::combinator::map_res;
-use nom::multi::separated_list1;
-use nom::IResult;
 use std::str::FromStr;
 
+use advent_of_code::util::parse::{finish, key_values, parse_lines};
+use miette::Report;
+
 advent_of_code::solution!(7);
 
 #[derive(Debug, PartialEq)]
@@ -13,11 +12,15 @@ struct Test {
 }
 
 impl FromStr for Test {
-    type Err = ();
+    type Err = Report;
 
+    // example line:  3267: 81 40 27
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (_, test) = parse_line(s).map_err(|_| ())?;
-        Ok(test)
+        let (test_value, numbers) = finish(s, key_values(s))?;
+        Ok(Test {
+            test_value,
+            numbers,
+        })
     }
 }
 
@@ -28,7 +31,8 @@ fn validate(current: i64, numbers: &[i64], include_concat: bool) -> bool {
         return current == 0;
     }
 
-    if current < 0 {
+    // every operator keeps the running value positive, so a non-positive remainder can't be solved.
+    if current <= 0 {
         return false;
     }
 
@@ -52,27 +56,8 @@ fn validate(current: i64, numbers: &[i64], include_concat: bool) -> bool {
         )
 }
 
-// example line:  3267: 81 40 27
-fn parse_line(input: &str) -> IResult<&str, Test> {
-    let (input, test_value) = map_res(digit1, str::parse)(input)?;
-    let (input, _) = char(':')(input)?;
-    let (input, _) = space1(input)?;
-    let (input, numbers) = separated_list1(space1, map_res(digit1, str::parse))(input)?;
-
-    Ok((
-        input,
-        Test {
-            test_value,
-            numbers,
-        },
-    ))
-}
-
-pub fn part_one(input: &str) -> Option<u32> {
-    let tests = input
-        .lines()
-        .map(|line| line.parse::<Test>().unwrap())
-        .collect::<Vec<_>>();
+pub fn part_one(input: &str) -> miette::Result<i64> {
+    let tests: Vec<Test> = parse_lines(input, str::parse)?;
 
     let mut result: i64 = 0;
     for test in tests {
@@ -80,14 +65,11 @@ pub fn part_one(input: &str) -> Option<u32> {
             result += test.test_value;
         }
     }
-    Some(result as u32)
+    Ok(result)
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    let tests = input
-        .lines()
-        .map(|line| line.parse::<Test>().unwrap())
-        .collect::<Vec<_>>();
+pub fn part_two(input: &str) -> miette::Result<i64> {
+    let tests: Vec<Test> = parse_lines(input, str::parse)?;
 
     let mut result: i64 = 0;
     for test in tests {
@@ -95,22 +77,79 @@ pub fn part_two(input: &str) -> Option<u32> {
             result += test.test_value;
         }
     }
-    Some(result as u32)
+    Ok(result)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use advent_of_code::util::differential::assert_equivalent;
+    use advent_of_code::util::rng::Rng;
 
     #[test]
     fn test_part_one() {
         let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(3749));
+        assert_eq!(result.unwrap(), 3749);
     }
 
     #[test]
     fn test_part_two() {
         let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(11387));
+        assert_eq!(result.unwrap(), 11387);
+    }
+
+    /// Tries every combination of operators from left to right.
+    fn validate_brute_force(test_value: i64, numbers: &[i64], include_concat: bool) -> bool {
+        let operators: u32 = if include_concat { 3 } else { 2 };
+        let combinations = operators.pow(numbers.len() as u32 - 1);
+
+        (0..combinations).any(|mut combination| {
+            let result = numbers[1..].iter().fold(numbers[0], |acc, &n| {
+                let operator = combination % operators;
+                combination /= operators;
+                match operator {
+                    0 => acc + n,
+                    1 => acc * n,
+                    _ => acc * 10_i64.pow(n.ilog10() + 1) + n,
+                }
+            });
+            result == test_value
+        })
+    }
+
+    fn generate_test(rng: &mut Rng) -> (i64, Vec<i64>) {
+        let numbers: Vec<i64> = (0..rng.range(1..6)).map(|_| rng.range(1..20)).collect();
+        // bias towards solvable equations by sometimes picking an actual result.
+        let test_value = if rng.chance(0.5) {
+            numbers.iter().fold(0, |acc, &n| match rng.below(3) {
+                0 => acc + n,
+                1 => acc * n,
+                _ => acc * 10_i64.pow(n.ilog10() + 1) + n,
+            })
+        } else {
+            rng.range(1..1000)
+        };
+        (test_value, numbers)
+    }
+
+    #[test]
+    fn test_validate_against_brute_force() {
+        // shrinking may leave the puzzle's domain of positive numbers, which both sides reject.
+        let is_valid = |test_value: i64, numbers: &[i64]| {
+            test_value > 0 && !numbers.is_empty() && numbers.iter().all(|&n| n > 0)
+        };
+
+        for include_concat in [false, true] {
+            assert_equivalent(
+                generate_test,
+                |(test_value, numbers)| {
+                    is_valid(*test_value, numbers)
+                        && validate_brute_force(*test_value, numbers, include_concat)
+                },
+                |(test_value, numbers)| {
+                    is_valid(*test_value, numbers) && validate(*test_value, numbers, include_concat)
+                },
+            );
+        }
     }
 }
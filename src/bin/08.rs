@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 
+use advent_of_code::util::{grid::Grid as CellGrid, point::Point};
 use miette::Error;
 
 advent_of_code::solution!(8);
@@ -16,8 +17,7 @@ struct Frequency(char);
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 struct Antenna {
     kind: Frequency,
-    row: i64,
-    col: i64,
+    position: Point<i64>,
 }
 
 #[derive(Debug)]
@@ -26,64 +26,76 @@ struct Grid {
     antennas: HashMap<Frequency, Vec<Antenna>>,
 }
 
-fn parse_input(input: &str) -> Grid {
-    let mut nodes = Vec::new();
-    let mut antennas = HashMap::new();
-
-    for (row, line) in input.lines().enumerate() {
-        let mut node_row = Vec::new();
-
-        for (col, c) in line.chars().enumerate() {
-            node_row.push(Node::Node);
-            if c != '.' {
-                antennas
-                    .entry(Frequency(c))
-                    .or_insert(Vec::new())
-                    .push(Antenna {
-                        kind: Frequency(c),
-                        row: row as i64,
-                        col: col as i64,
-                    });
-            };
+fn parse_input(input: &str) -> miette::Result<Grid> {
+    let cells = CellGrid::parse_with(input, |b| {
+        if b == b'.' || b.is_ascii_alphanumeric() {
+            Ok(b)
+        } else {
+            Err("expected `.` or an antenna")
         }
-
-        nodes.push(node_row);
-    }
-
-    Grid { nodes, antennas }
+    })
+    .map_err(|e| e.report(input))?;
+    let nodes = cells
+        .rows()
+        .map(|row| row.iter().map(|_| Node::Node).collect())
+        .collect();
+
+    let antennas = cells
+        .group_by_value()
+        .into_iter()
+        .filter(|&(c, _)| c != b'.')
+        .map(|(c, points)| {
+            let kind = Frequency(c as char);
+            let antennas = points
+                .into_iter()
+                .map(|point| Antenna {
+                    kind,
+                    position: Point::new(point.x as i64, point.y as i64),
+                })
+                .collect();
+            (kind, antennas)
+        })
+        .collect();
+
+    Ok(Grid { nodes, antennas })
 }
 
 impl Grid {
-    fn contains(&self, row: usize, col: usize) -> bool {
-        row < self.nodes.len() && col < self.nodes[0].len()
+    fn contains(&self, point: Point<i64>) -> bool {
+        point.y >= 0
+            && point.x >= 0
+            && (point.y as usize) < self.nodes.len()
+            && (point.x as usize) < self.nodes[0].len()
     }
 
-    fn find_resonant_frequency_antinodes(&mut self, frequency: Frequency) -> Result<(), Error> {
-        if self.antennas.get(&frequency).is_none() {
+    fn mark_antinode(&mut self, point: Point<i64>) {
+        self.nodes[point.y as usize][point.x as usize] = Node::Antinode;
+    }
+
+    fn antennas(&self, frequency: Frequency) -> Result<Vec<Antenna>, Error> {
+        let Some(antennas) = self.antennas.get(&frequency) else {
             return Err(miette::miette!("No antennas with that frequency"));
-        }
+        };
 
-        if self.antennas.get(&frequency).unwrap().len() < 2 {
-            return Err(miette::miette!("Not enough antennas with that frequency"));
-        }
+        Ok(antennas.clone())
+    }
+
+    fn find_resonant_frequency_antinodes(&mut self, frequency: Frequency) -> Result<(), Error> {
+        let antennas = self.antennas(frequency)?;
 
-        for antenna_1 in self.antennas.get(&frequency).unwrap() {
-            for antenna_2 in self.antennas.get(&frequency).unwrap() {
+        for antenna_1 in &antennas {
+            for antenna_2 in &antennas {
                 if antenna_1 == antenna_2 {
                     continue;
                 }
 
-                let row_diff = antenna_2.row as i64 - antenna_1.row as i64;
-                let col_diff = antenna_2.col as i64 - antenna_1.col as i64;
-
-                let mut antinode = (antenna_2.row, antenna_2.col);
-
-                loop {
-                    self.nodes[antinode.0 as usize][antinode.1 as usize] = Node::Antinode;
-                    antinode = (antinode.0 + row_diff, antinode.1 + col_diff);
-                    if !self.contains(antinode.0 as usize, antinode.1 as usize) {
-                        break;
-                    }
+                // every lattice point in line with both antennas, starting at the first one and
+                // walking past the second one. The reversed pair covers the other direction.
+                let step = (antenna_2.position - antenna_1.position).reduce();
+                let mut antinode = antenna_1.position;
+                while self.contains(antinode) {
+                    self.mark_antinode(antinode);
+                    antinode += step;
                 }
             }
         }
@@ -92,32 +104,17 @@ impl Grid {
     }
 
     fn find_antinodes(&mut self, frequency: Frequency) -> Result<(), Error> {
-        if self.antennas.get(&frequency).is_none() {
-            return Err(miette::miette!("No antennas with that frequency"));
-        }
-
-        if self.antennas.get(&frequency).unwrap().len() < 2 {
-            return Err(miette::miette!("Not enough antennas with that frequency"));
-        }
+        let antennas = self.antennas(frequency)?;
 
-        for antenna_1 in self.antennas.get(&frequency).unwrap() {
-            for antenna_2 in self.antennas.get(&frequency).unwrap() {
+        for antenna_1 in &antennas {
+            for antenna_2 in &antennas {
                 if antenna_1 == antenna_2 {
                     continue;
                 }
 
-                let row_diff = antenna_2.row as i64 - antenna_1.row as i64;
-                let col_diff = antenna_2.col as i64 - antenna_1.col as i64;
-
-                let (possible_row, possible_col) =
-                    (antenna_2.row + row_diff, antenna_2.col + col_diff);
-
-                if possible_row < 0 || possible_col < 0 {
-                    continue;
-                }
-
-                if self.contains(possible_row as usize, possible_col as usize) {
-                    self.nodes[possible_row as usize][possible_col as usize] = Node::Antinode;
+                let antinode = antenna_2.position * 2 - antenna_1.position;
+                if self.contains(antinode) {
+                    self.mark_antinode(antinode);
                 }
             }
         }
@@ -134,22 +131,22 @@ impl Grid {
     }
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
-    let mut grid = parse_input(input);
+pub fn part_one(input: &str) -> miette::Result<Option<u32>> {
+    let mut grid = parse_input(input)?;
     let frequencies: Vec<_> = grid.antennas.keys().cloned().collect();
     for frequency in frequencies {
-        grid.find_antinodes(frequency).unwrap();
+        grid.find_antinodes(frequency)?;
     }
-    Some(grid.count_antinodes() as u32)
+    Ok(Some(grid.count_antinodes() as u32))
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    let mut grid = parse_input(input);
+pub fn part_two(input: &str) -> miette::Result<Option<u32>> {
+    let mut grid = parse_input(input)?;
     let frequencies: Vec<_> = grid.antennas.keys().cloned().collect();
     for frequency in frequencies {
-        grid.find_resonant_frequency_antinodes(frequency).unwrap();
+        grid.find_resonant_frequency_antinodes(frequency)?;
     }
-    Some(grid.count_antinodes() as u32)
+    Ok(Some(grid.count_antinodes() as u32))
 }
 
 #[cfg(test)]
@@ -159,12 +156,23 @@ mod tests {
     #[test]
     fn test_part_one() {
         let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(14));
+        assert_eq!(result.unwrap(), Some(14));
     }
 
     #[test]
     fn test_part_two() {
         let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(34));
+        assert_eq!(result.unwrap(), Some(34));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = part_one("..a.\n.#..").unwrap_err();
+        assert_eq!(error.labels().unwrap().next().unwrap().offset(), 6);
+    }
+
+    #[test]
+    fn test_lone_antenna() {
+        assert_eq!(part_one("..b.\n.a..\n..a.\n....").unwrap(), Some(2));
     }
 }
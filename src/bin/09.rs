@@ -1,6 +1,8 @@
+use advent_of_code::util::parse::error_at;
+use advent_of_code::util::rng::Rng;
 use miette::Error;
 
-advent_of_code::solution!(9);
+advent_of_code::solution!(9, generator = generate);
 
 struct Blocks(Vec<Option<File>>);
 struct File(u64);
@@ -32,13 +34,25 @@ impl std::fmt::Debug for Blocks {
     }
 }
 
-fn parse_input(input: &str) -> Blocks {
+/// Lengths of the alternating files and free spaces of the disk map.
+fn parse_disk_map(input: &str) -> miette::Result<Vec<usize>> {
+    input
+        .trim_end()
+        .char_indices()
+        .map(|(i, c)| {
+            c.to_digit(10)
+                .map(|d| d as usize)
+                .ok_or_else(|| error_at(input, i, c.len_utf8(), "expected a digit"))
+        })
+        .collect()
+}
+
+fn parse_input(input: &str) -> miette::Result<Blocks> {
     let mut blocks = Vec::new();
 
     let mut parsing_file = true;
     let mut current_file_id = 0;
-    for c in input.chars() {
-        let c_num = c.to_digit(10).map(|d| d as usize).expect("Failed to parse");
+    for c_num in parse_disk_map(input)? {
         if parsing_file {
             for _ in 0..c_num {
                 blocks.push(Some(File(current_file_id)));
@@ -54,7 +68,7 @@ fn parse_input(input: &str) -> Blocks {
         parsing_file = !parsing_file;
     }
 
-    Blocks(blocks)
+    Ok(Blocks(blocks))
 }
 
 #[derive(Debug, Copy, Clone)]
@@ -70,7 +84,7 @@ struct BlankSpace {
     length: usize,
 }
 
-fn parse_files_and_blanks(input: &str) -> (Vec<FileBlock>, Vec<BlankSpace>) {
+fn parse_files_and_blanks(input: &str) -> miette::Result<(Vec<FileBlock>, Vec<BlankSpace>)> {
     let mut files = Vec::new();
     let mut blanks = Vec::new();
 
@@ -78,8 +92,7 @@ fn parse_files_and_blanks(input: &str) -> (Vec<FileBlock>, Vec<BlankSpace>) {
     let mut current_file_id = 0;
     let mut position = 0;
 
-    for c in input.chars() {
-        let c_num = c.to_digit(10).map(|d| d as usize).expect("Failed to parse");
+    for c_num in parse_disk_map(input)? {
         if parsing_file {
             let file = FileBlock {
                 file_id: current_file_id,
@@ -99,7 +112,7 @@ fn parse_files_and_blanks(input: &str) -> (Vec<FileBlock>, Vec<BlankSpace>) {
         position += c_num;
     }
 
-    (files, blanks)
+    Ok((files, blanks))
 }
 
 fn compress(blocks: &mut Blocks) -> Result<(), Error> {
@@ -123,13 +136,13 @@ fn compress(blocks: &mut Blocks) -> Result<(), Error> {
     Ok(())
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
-    let mut blocks = parse_input(input);
+pub fn part_one(input: &str) -> miette::Result<Option<u32>> {
+    let mut blocks = parse_input(input)?;
     let _ = compress(&mut blocks);
 
     let checksum = blocks.checksum().expect("Failed to calculate checksum");
 
-    Some(checksum as u32)
+    Ok(Some(checksum as u32))
 }
 
 fn compress_p2(
@@ -146,7 +159,7 @@ fn compress_p2(
         let mut should_remove_blank = false;
 
         loop {
-            if blank_idx > blanks.len() {
+            if blank_idx >= blanks.len() {
                 break;
             }
             let blank = blanks.get_mut(blank_idx).expect("Failed to get blank");
@@ -177,33 +190,120 @@ fn compress_p2(
     Ok(files.clone())
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    let (mut files, mut blanks) = parse_files_and_blanks(input);
-    let compressed_files = compress_p2(&mut files, &mut blanks).expect("Failed to compress");
+pub fn part_two(input: &str) -> miette::Result<Option<u32>> {
+    let (mut files, mut blanks) = parse_files_and_blanks(input)?;
+    let compressed_files = compress_p2(&mut files, &mut blanks)?;
+
+    Ok(Some(files_checksum(&compressed_files) as u32))
+}
 
+fn files_checksum(files: &[FileBlock]) -> u64 {
     let mut total: u64 = 0;
-    for file in compressed_files.iter() {
+    for file in files.iter() {
         for x in file.start_position..(file.start_position + file.length) {
             total += (x as u64) * (file.file_id as u64);
         }
     }
 
-    Some(total as u32)
+    total
+}
+
+/// Generates a disk map of `size` digits (at least one), alternating files of length 1-9 and
+/// free space of 0-9. Even sizes end with free space. The map has no trailing newline.
+pub fn generate(size: usize, rng: &mut Rng) -> String {
+    (0..size.max(1))
+        .map(|i| {
+            let min = if i % 2 == 0 { 1 } else { 0 };
+            char::from(b'0' + rng.range(min..10) as u8)
+        })
+        .collect()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use advent_of_code::template::generator::assert_generator;
+    use advent_of_code::util::differential::assert_equivalent;
 
     #[test]
     fn test_part_one() {
         let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(1928));
+        assert_eq!(result.unwrap(), Some(1928));
     }
 
     #[test]
     fn test_part_two() {
         let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(2858));
+        assert_eq!(result.unwrap(), Some(2858));
+    }
+
+    /// Moves whole files block by block on the expanded disk.
+    fn compress_p2_reference(disk_map: &[u8]) -> u64 {
+        let mut disk: Vec<Option<usize>> = Vec::new();
+        for (i, &length) in disk_map.iter().enumerate() {
+            let block = if i % 2 == 0 { Some(i / 2) } else { None };
+            disk.extend(std::iter::repeat_n(block, length as usize));
+        }
+
+        for file_id in (0..disk_map.len().div_ceil(2)).rev() {
+            let Some(start) = disk.iter().position(|&b| b == Some(file_id)) else {
+                continue;
+            };
+            let length = disk_map[file_id * 2] as usize;
+
+            let free = (0..start).find(|&i| disk[i..i + length].iter().all(Option::is_none));
+            if let Some(free) = free {
+                disk[free..free + length].fill(Some(file_id));
+                disk[start..start + length].fill(None);
+            }
+        }
+
+        disk.iter()
+            .enumerate()
+            .filter_map(|(i, b)| b.map(|id| (i * id) as u64))
+            .sum()
+    }
+
+    #[test]
+    fn test_compress_p2_against_reference() {
+        // shrinking may remove single digits, turning blanks into files, or shrink files to
+        // length 0, which the puzzle never contains. Both sides skip such disk maps.
+        let is_valid = |disk_map: &[u8]| {
+            !disk_map.is_empty()
+                && disk_map.iter().all(|&d| d < 10)
+                && disk_map.iter().step_by(2).all(|&d| d > 0)
+        };
+
+        assert_equivalent(
+            |rng| {
+                (0..rng.range(1..20))
+                    .map(|i| rng.range(i64::from(i % 2 == 0)..10) as u8)
+                    .collect::<Vec<u8>>()
+            },
+            |disk_map| is_valid(disk_map).then(|| compress_p2_reference(disk_map)),
+            |disk_map| {
+                is_valid(disk_map).then(|| {
+                    let input: String = disk_map.iter().map(|d| char::from(b'0' + d)).collect();
+                    let (mut files, mut blanks) = parse_files_and_blanks(&input).unwrap();
+                    files_checksum(&compress_p2(&mut files, &mut blanks).unwrap())
+                })
+            },
+        );
+    }
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(generate(20, &mut Rng::new(1)).len(), 20);
+        assert_generator(generate, |input| {
+            let disk_map: Vec<u8> = input.bytes().map(|b| b - b'0').collect();
+            part_one(input).unwrap().is_some()
+                && part_two(input).unwrap() == Some(compress_p2_reference(&disk_map) as u32)
+        });
+    }
+
+    #[test]
+    fn test_parse_error_points_at_character() {
+        let error = part_one("12x4\n").unwrap_err();
+        assert_eq!(error.labels().unwrap().next().unwrap().offset(), 2);
     }
 }
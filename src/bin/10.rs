@@ -1,157 +1,81 @@
-use std::{collections::HashSet, fmt::Debug};
+use std::fmt::Debug;
 
-advent_of_code::solution!(10);
+use advent_of_code::util::{
+    grid::Grid,
+    point::Point,
+    rng::Rng,
+    search::{bfs, bfs_all},
+};
 
-#[derive(Debug)]
-enum Direction {
-    North,
-    East,
-    South,
-    West,
-}
-
-const DIRECTIONS: [Direction; 4] = [
-    Direction::North,
-    Direction::East,
-    Direction::South,
-    Direction::West,
-];
-
-#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
-struct Position {
-    row: i32,
-    col: i32,
-    height: usize,
-}
-
-impl Position {
-    fn new(row: usize, col: usize, height: usize) -> Self {
-        Self {
-            row: row as i32,
-            col: col as i32,
-            height,
-        }
-    }
-}
+advent_of_code::solution!(10, generator = generate);
 
 struct TrailMap {
-    positions: Vec<Vec<Position>>,
-    trailheads: Vec<Position>,
+    heights: Grid<u8>,
+    trailheads: Vec<Point>,
 }
 
 impl Debug for TrailMap {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "TrailMap:\n")?;
-        for row in &self.positions {
-            for pos in row {
-                write!(f, "{}", pos.height)?;
-            }
-            writeln!(f)?;
-        }
+        writeln!(f, "TrailMap (trailheads marked with `T`):")?;
+        self.heights
+            .renderer(|&height| char::from(b'0' + height))
+            .mark(self.trailheads.iter().copied(), 'T')
+            .write_to(f)?;
 
         write!(f, "\nTrailheads:\n")?;
         for trailhead in &self.trailheads {
-            write!(f, "\t{:?}\n", trailhead)?;
+            writeln!(f, "\t{:?}", trailhead)?;
         }
         Ok(())
     }
 }
 
 fn parse_input(input: &str) -> TrailMap {
-    let mut positions: Vec<Vec<Position>> = Vec::new();
-    let mut trailheads: Vec<Position> = Vec::new();
-    for (row_idx, row) in input.lines().enumerate() {
-        let mut col_positions = Vec::new();
-        for (col_idx, col) in row.chars().enumerate() {
-            let height = col.to_digit(10).unwrap() as usize;
-            let position = Position::new(row_idx, col_idx, height);
-            col_positions.push(position);
-            if height == 0 {
-                trailheads.push(position);
-            }
-        }
-        positions.push(col_positions);
-    }
+    let heights = Grid::parse_with(input, |b| {
+        (b as char)
+            .to_digit(10)
+            .map(|height| height as u8)
+            .ok_or("expected a height between 0 and 9")
+    })
+    .unwrap();
+
+    let trailheads = heights.find_all(&0).collect();
 
     TrailMap {
-        positions,
+        heights,
         trailheads,
     }
 }
 
 impl TrailMap {
-    fn get_position(&self, row: i32, col: i32) -> Option<Position> {
-        if row < 0 || col < 0 {
-            return None;
-        }
-
-        self.positions
-            .get(row as usize)
-            .and_then(|r| r.get(col as usize))
-            .copied()
-    }
-
-    fn move_in(&self, position: &Position, direction: Direction) -> Option<Position> {
-        let new_position = match direction {
-            Direction::North => self.get_position(position.row - 1, position.col),
-            Direction::East => self.get_position(position.row, position.col + 1),
-            Direction::South => self.get_position(position.row + 1, position.col),
-            Direction::West => self.get_position(position.row, position.col - 1),
-        };
-        new_position
+    /// Neighbors of `point` that are exactly one step higher.
+    fn uphill_neighbors(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        let height = self.heights[point];
+        self.heights
+            .orthogonal_neighbors(point)
+            .filter(move |&next| self.heights[next] == height + 1)
     }
 
-    fn score_trailhead(&self, trailhead: &Position) -> usize {
-        let mut trail_ends: HashSet<Position> = HashSet::new();
-
-        let mut working_positions: Vec<Position> = Vec::new();
-        working_positions.push(*trailhead);
-
-        while let Some(pos) = working_positions.pop() {
-            for direction in DIRECTIONS {
-                if let Some(next) = self.move_in(&pos, direction) {
-                    match next.height {
-                        9 if pos.height == 8 => {
-                            trail_ends.insert(next);
-                        }
-                        height if height > 0 && height - 1 == pos.height => {
-                            working_positions.push(next);
-                        }
-                        _ => {}
-                    }
-                }
-            }
-        }
+    fn score_trailhead(&self, trailhead: Point) -> usize {
+        let search = bfs(trailhead, |&pos| self.uphill_neighbors(pos), |_| false);
 
-        trail_ends.len()
+        search
+            .distances()
+            .keys()
+            .filter(|&&pos| self.heights[pos] == 9)
+            .count()
     }
 
-    fn find_distinct_trails(&self, trailhead: &Position) -> u32 {
-        let mut trails: HashSet<Vec<Position>> = HashSet::new();
-
-        let mut stack: Vec<Vec<Position>> = Vec::new();
-        stack.push(vec![*trailhead]);
-
-        while let Some(current_path) = stack.pop() {
-            let current_pos = *current_path.last().unwrap();
-
-            if current_pos.height == 9 {
-                trails.insert(current_path.clone());
-                continue;
-            }
-
-            for direction in DIRECTIONS {
-                if let Some(next) = self.move_in(&current_pos, direction) {
-                    if next.height > current_pos.height && next.height - 1 == current_pos.height {
-                        let mut new_path = current_path.clone();
-                        new_path.push(next);
-                        stack.push(new_path);
-                    }
-                }
-            }
-        }
+    fn find_distinct_trails(&self, trailhead: Point) -> u32 {
+        // every step climbs by one, so every trail is a shortest path to its end.
+        let search = bfs_all(trailhead, |&pos| self.uphill_neighbors(pos), |_| false);
+        let trail_ends = search
+            .distances()
+            .keys()
+            .filter(|&&pos| self.heights[pos] == 9)
+            .copied();
 
-        trails.len() as u32
+        search.count_paths(trail_ends.collect::<Vec<_>>()) as u32
     }
 }
 
@@ -161,7 +85,7 @@ pub fn part_one(input: &str) -> Option<u32> {
     let score: u32 = trailmap
         .trailheads
         .iter()
-        .map(|trailhead| trailmap.score_trailhead(trailhead) as u32)
+        .map(|trailhead| trailmap.score_trailhead(*trailhead) as u32)
         .sum();
 
     Some(score)
@@ -172,15 +96,27 @@ pub fn part_two(input: &str) -> Option<u32> {
     let score: u32 = trailmap
         .trailheads
         .iter()
-        .map(|trailhead| trailmap.find_distinct_trails(trailhead) as u32)
+        .map(|trailhead| trailmap.find_distinct_trails(*trailhead))
         .sum();
 
     Some(score)
 }
 
+/// Generates a square topographic map of random heights.
+pub fn generate(size: usize, rng: &mut Rng) -> String {
+    let size = size.max(1);
+    let mut input = String::with_capacity(size * (size + 1));
+    for _ in 0..size {
+        input.extend((0..size).map(|_| char::from(b'0' + rng.below(10) as u8)));
+        input.push('\n');
+    }
+    input
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use advent_of_code::template::generator::{assert_generator, is_square_grid};
 
     #[test]
     fn test_part_one() {
@@ -193,4 +129,11 @@ mod tests {
         let result = part_two(&advent_of_code::template::read_file("examples", DAY));
         assert_eq!(result, Some(81));
     }
+
+    #[test]
+    fn test_generate() {
+        assert_generator(generate, |input| {
+            is_square_grid(input, |c| c.is_ascii_digit())
+        });
+    }
 }
@@ -1,10 +1,12 @@
 use std::collections::HashMap;
 use std::mem;
 
+use advent_of_code::util::math::{digit_count, split_digits};
+use advent_of_code::util::rng::Rng;
 use nom::character::complete;
 use nom::{character::complete::space1, multi::separated_list1, IResult};
 
-advent_of_code::solution!(11);
+advent_of_code::solution!(11, variants = [part_one_brute_force => 1], generator = generate);
 
 #[derive(Debug, Hash, Eq, Copy, Clone)]
 struct Stone(u64);
@@ -21,9 +23,7 @@ impl Stone {
             return (Stone(1), None);
         }
 
-        let num_digits = { (self.0 as f64).log10().floor() as u64 + 1 };
-
-        if num_digits % 2 == 0 {
+        if digit_count(self.0).is_multiple_of(2) {
             let (left, right) = split_number(self.0);
             return (Stone(left), Some(Stone(right)));
         }
@@ -34,19 +34,13 @@ impl Stone {
 }
 
 fn split_number(n: u64) -> (u64, u64) {
-    let digit_count = (n as f64).log10().floor() as u32 + 1;
+    let digit_count = digit_count(n);
     assert!(
-        digit_count % 2 == 0,
+        digit_count.is_multiple_of(2),
         "Number must have even number of digits"
     );
 
-    let half_digits = digit_count / 2;
-    let divisor = 10_u32.pow(half_digits);
-
-    let right_half = n % divisor as u64;
-    let left_half = n / divisor as u64;
-
-    (left_half, right_half)
+    split_digits(n, digit_count / 2)
 }
 
 fn parse_input(input: &str) -> IResult<&str, Vec<Stone>> {
@@ -55,35 +49,37 @@ fn parse_input(input: &str) -> IResult<&str, Vec<Stone>> {
     Ok((input, numbers.into_iter().map(|n| Stone(n)).collect()))
 }
 
-// pub fn part_one(input: &str) -> Option<u64> {
-//     let (_, mut stones) = parse_input(input).unwrap();
-//     let mut new_stones: Vec<Stone> = Vec::new();
-
-//     // cache
-//     let mut seen: HashMap<Stone, (Stone, Option<Stone>)> = HashMap::new();
-
-//     for i in 0..25 {
-//         new_stones.clear();
-//         for stone in &stones {
-//             if let Some((new_stone, new_stone_right)) = seen.get(stone) {
-//                 new_stones.push(*new_stone);
-//                 if let Some(right) = new_stone_right {
-//                     new_stones.push(*right);
-//                 }
-//                 continue;
-//             }
-
-//             let (new_stone, new_stone_right) = stone.apply_blink();
-//             seen.insert(*stone, (new_stone, new_stone_right));
-//             new_stones.push(new_stone);
-//             if let Some(right) = new_stone_right {
-//                 new_stones.push(right);
-//             }
-//         }
-//         mem::swap(&mut stones, &mut new_stones);
-//     }
-//     Some(stones.len() as u64)
-// }
+/// Simulates every blink on the full list of stones. Kept as a reference for `part_one`.
+pub fn part_one_brute_force(input: &str) -> Option<u64> {
+    let (_, mut stones) = parse_input(input).unwrap();
+    let mut new_stones: Vec<Stone> = Vec::new();
+
+    // cache
+    let mut seen: HashMap<Stone, (Stone, Option<Stone>)> = HashMap::new();
+
+    for _ in 0..25 {
+        new_stones.clear();
+        for stone in &stones {
+            if let Some((new_stone, new_stone_right)) = seen.get(stone) {
+                new_stones.push(*new_stone);
+                if let Some(right) = new_stone_right {
+                    new_stones.push(*right);
+                }
+                continue;
+            }
+
+            let (new_stone, new_stone_right) = stone.apply_blink();
+            seen.insert(*stone, (new_stone, new_stone_right));
+            new_stones.push(new_stone);
+            if let Some(right) = new_stone_right {
+                new_stones.push(right);
+            }
+        }
+        mem::swap(&mut stones, &mut new_stones);
+    }
+    Some(stones.len() as u64)
+}
+
 pub fn part_one(input: &str) -> Option<u64> {
     let (_, stones) = parse_input(input).unwrap();
 
@@ -125,9 +121,7 @@ fn count(stone: &Stone, steps_remaining: usize, cache: &mut HashMap<(Stone, usiz
         return count(&Stone(1), steps_remaining - 1, cache);
     }
 
-    let num_digits = { (stone.0 as f64).log10().floor() as u64 + 1 };
-
-    if num_digits % 2 == 0 {
+    if digit_count(stone.0).is_multiple_of(2) {
         let (left, right) = split_number(stone.0);
 
         let result = count(&Stone(left), steps_remaining - 1, cache)
@@ -142,9 +136,18 @@ fn count(stone: &Stone, steps_remaining: usize, cache: &mut HashMap<(Stone, usiz
     return result;
 }
 
+/// Generates a line of `size` stones with up to six digits each.
+pub fn generate(size: usize, rng: &mut Rng) -> String {
+    let stones: Vec<String> = (0..size.max(1))
+        .map(|_| rng.below(1_000_000).to_string())
+        .collect();
+    stones.join(" ") + "\n"
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use advent_of_code::template::generator::assert_generator;
 
     #[test]
     fn test_part_one() {
@@ -152,15 +155,30 @@ mod tests {
         assert_eq!(result, Some(55312));
     }
 
+    #[test]
+    fn test_part_one_brute_force() {
+        let result = part_one_brute_force(&advent_of_code::template::read_file("examples", DAY));
+        assert_eq!(result, Some(55312));
+    }
+
     #[test]
     fn test_part_two() {
         let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
+        assert_eq!(result, Some(65601038650482));
     }
 
     #[test]
     fn test_split_number() {
         assert_eq!(split_number(123456), (123, 456));
         assert_eq!(split_number(12345678), (1234, 5678));
+        assert_eq!(split_number(10_000_000_000_000_000_000), (1_000_000_000, 0));
+        assert_eq!(split_number(1_000_000_000_000_000), (10_000_000, 0));
+    }
+
+    #[test]
+    fn test_generate() {
+        assert_generator(generate, |input| {
+            part_one(input) == part_one_brute_force(input)
+        });
     }
 }
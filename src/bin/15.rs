@@ -1,10 +1,14 @@
 advent_of_code::solution!(15);
-use std::{
-    fmt::{self, Debug},
-    str::FromStr,
-};
+use std::fmt::{self, Debug};
 
-use miette::Error;
+#[cfg(test)]
+use advent_of_code::util::image::Image;
+use advent_of_code::util::{
+    grid::Grid,
+    parse::{error_at, error_in, parse_lines, relocate, sections},
+    point::{Direction, Point as GridPoint},
+};
+use miette::{miette, Error};
 
 #[derive(Copy, Clone)]
 struct Point {
@@ -35,6 +39,12 @@ impl Point {
     }
 }
 
+impl From<Point> for GridPoint {
+    fn from(point: Point) -> Self {
+        GridPoint::new(point.col, point.row)
+    }
+}
+
 impl Debug for Point {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "({}, {})", self.row, self.col)
@@ -49,113 +59,83 @@ enum Block {
     Robot,
 }
 
-impl Debug for Block {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+impl Block {
+    fn symbol(self) -> char {
         match self {
-            Block::Empty => write!(f, "_"),
-            Block::Wall => write!(f, "#"),
-            Block::Box => write!(f, "O"),
-            Block::Robot => write!(f, "@"),
+            Block::Empty => '_',
+            Block::Wall => '#',
+            Block::Box => 'O',
+            Block::Robot => '@',
         }
     }
 }
 
-impl FromStr for Block {
-    type Err = ();
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "." => Ok(Block::Empty),
-            "#" => Ok(Block::Wall),
-            "O" => Ok(Block::Box),
-            "@" => Ok(Block::Robot),
-            _ => Err(()),
-        }
-    }
-}
-
-#[derive(Copy, Clone)]
-enum Direction {
-    North,
-    South,
-    East,
-    West,
-}
-
-impl Debug for Direction {
+impl Debug for Block {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match self {
-            Direction::North => write!(f, "^"),
-            Direction::South => write!(f, "v"),
-            Direction::East => write!(f, ">"),
-            Direction::West => write!(f, "<"),
-        }
+        write!(f, "{}", self.symbol())
     }
 }
 
-impl FromStr for Direction {
-    type Err = ();
+impl TryFrom<u8> for Block {
+    type Error = &'static str;
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "^" => Ok(Direction::North),
-            "v" => Ok(Direction::South),
-            ">" => Ok(Direction::East),
-            "<" => Ok(Direction::West),
-            _ => Err(()),
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            b'.' => Ok(Block::Empty),
+            b'#' => Ok(Block::Wall),
+            b'O' => Ok(Block::Box),
+            b'@' => Ok(Block::Robot),
+            _ => Err("expected one of `.#O@`"),
         }
     }
 }
 
-struct Grid {
-    grid: Vec<Vec<Block>>,
+struct Warehouse {
+    grid: Grid<Block>,
 }
 
-impl Debug for Grid {
+impl Debug for Warehouse {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         writeln!(f, "\n")?;
-        for row in &self.grid {
-            for pos in row {
-                write!(f, "{:?}", pos)?;
-            }
-            writeln!(f)?;
-        }
-
-        Ok(())
+        self.grid.renderer(|block| block.symbol()).write_to(f)
     }
 }
 
-impl Grid {
+impl Warehouse {
     fn robot_position(&self) -> Point {
-        self.grid
-            .iter()
-            .enumerate()
-            .find_map(|(row, line)| {
-                line.iter()
-                    .position(|pos| pos == &Block::Robot)
-                    .map(|col| Point {
-                        row: row as i32,
-                        col: col as i32,
-                    })
-            })
-            .unwrap()
+        let robot = self.grid.find(Block::Robot).unwrap();
+        Point {
+            row: robot.y,
+            col: robot.x,
+        }
+    }
+
+    /// Renders the warehouse for debugging, e.g. to record the robot's moves as an animation.
+    #[cfg(test)]
+    fn image(&self) -> Image {
+        Image::from_grid(&self.grid, |block| match block {
+            Block::Empty => [0, 0, 0],
+            Block::Wall => [128, 128, 128],
+            Block::Box => [200, 150, 60],
+            Block::Robot => [220, 40, 40],
+        })
     }
 
     fn at(&self, point: Point) -> Block {
-        self.grid[point.row as usize][point.col as usize]
+        self.grid[point.into()]
     }
 
     fn set_at(&mut self, point: Point, block: Block) -> Result<(), Error> {
-        self.grid[point.row as usize][point.col as usize] = block;
+        self.grid[point.into()] = block;
         Ok(())
     }
 
     fn get_total_gps(&self) -> u32 {
         let mut total: u32 = 0;
-        for (i, row) in self.grid.iter().enumerate() {
-            for (j, pos) in row.iter().enumerate() {
-                if pos == &Block::Box {
-                    total += (100 * i as u32) + j as u32;
+        for y in 0..self.grid.height() {
+            for x in 0..self.grid.width() {
+                if self.grid[GridPoint::new(x, y)] == Block::Box {
+                    total += (100 * y as u32) + x as u32;
                 }
             }
         }
@@ -213,47 +193,39 @@ impl Grid {
 // ########
 
 // <^^>>>vv<v>>v<<
-fn parse_input(input: &str) -> (Grid, Vec<Direction>) {
-    let mut lines = input.lines();
-    let mut grid_lines = Vec::new();
-    let mut directions = Vec::new();
-
-    // Parse grid
-    while let Some(line) = lines.next() {
-        if line.is_empty() {
-            break;
-        }
-        let row: Vec<Block> = line
-            .chars()
-            .map(|c| c.to_string().parse().unwrap())
-            .collect();
-        grid_lines.push(row);
+fn parse_input(input: &str) -> miette::Result<(Warehouse, Vec<Direction>)> {
+    let [map, moves] = sections(input)
+        .try_into()
+        .map_err(|_| miette!("expected a warehouse and moves separated by a blank line"))?;
+    let grid =
+        Grid::parse_with(map, Block::try_from).map_err(|e| relocate(e.report(map), input, map))?;
+    if grid.find(Block::Robot).is_none() {
+        return Err(error_in(input, map, "warehouse has no robot"));
     }
 
-    // Parse directions
     // Flatten all lines of directions into a single Vec
-    while let Some(dir_line) = lines.next() {
-        if dir_line.is_empty() {
-            continue;
-        }
-        let line_directions: Vec<Direction> = dir_line
-            .chars()
-            .map(|c| c.to_string().parse().unwrap())
-            .collect();
-        directions.extend(line_directions);
-    }
-
-    (Grid { grid: grid_lines }, directions)
+    let directions = parse_lines(moves, |line| {
+        line.bytes()
+            .enumerate()
+            .map(|(i, b)| Direction::try_from(b).map_err(|e| error_at(line, i, 1, e.to_string())))
+            .collect::<miette::Result<Vec<_>>>()
+    })
+    .map_err(|e| relocate(e, input, moves))?
+    .into_iter()
+    .flatten()
+    .collect();
+
+    Ok((Warehouse { grid }, directions))
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
-    let (mut grid, directions) = parse_input(input);
+pub fn part_one(input: &str) -> miette::Result<Option<u32>> {
+    let (mut warehouse, directions) = parse_input(input)?;
 
     for direction in directions {
-        grid.handle_robot_movement(direction).unwrap();
+        warehouse.handle_robot_movement(direction)?;
     }
 
-    Some(grid.get_total_gps())
+    Ok(Some(warehouse.get_total_gps()))
 }
 
 pub fn part_two(input: &str) -> Option<u32> {
@@ -263,11 +235,41 @@ pub fn part_two(input: &str) -> Option<u32> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use advent_of_code::util::image::FrameRecorder;
 
     #[test]
     fn test_part_one() {
         let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(10092));
+        assert_eq!(result.unwrap(), Some(10092));
+    }
+
+    #[test]
+    fn test_record_robot_moves() {
+        let (mut warehouse, directions) =
+            parse_input(&advent_of_code::template::read_file("examples", DAY)).unwrap();
+        let mut recorder = FrameRecorder::new().scale(4).delay(50);
+        recorder.record(warehouse.image());
+        for direction in &directions {
+            warehouse.handle_robot_movement(*direction).unwrap();
+            recorder.record(warehouse.image());
+        }
+        assert_eq!(recorder.len(), directions.len() + 1);
+
+        let mut gif = Vec::new();
+        recorder.write_gif(&mut gif).unwrap();
+        assert!(gif.starts_with(b"GIF89a"));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = part_one("#@.\n#.x\n\n<>").unwrap_err();
+        assert_eq!(error.labels().unwrap().next().unwrap().offset(), 6);
+
+        let error = part_one("#@.\n\n<>\nv?^").unwrap_err();
+        assert_eq!(error.labels().unwrap().next().unwrap().offset(), 9);
+
+        let error = part_one("#..\n\n<>").unwrap_err();
+        assert_eq!(error.labels().unwrap().next().unwrap().len(), 3);
     }
 
     #[test]
@@ -1,9 +1,28 @@
+use std::collections::HashSet;
+
 use advent_of_code::util::grid::*;
 use advent_of_code::util::point::*;
-
-advent_of_code::solution!(16);
-
-const DIRECTIONS: [Point; 4] = [RIGHT, DOWN, LEFT, UP];
+use advent_of_code::util::rng::Rng;
+use advent_of_code::util::search::{dijkstra_buckets, dijkstra_buckets_all};
+
+advent_of_code::solution!(16, generator = generate);
+
+/// A position in the maze and the direction the reindeer is facing.
+type State = (Point, Direction);
+
+/// Moves one step forward or turns left or right, together with the cost of the move.
+fn moves(
+    grid: &Grid<u8>,
+    (position, direction): State,
+) -> impl Iterator<Item = (State, usize)> + '_ {
+    [
+        ((position + direction.to_point(), direction), 1),
+        ((position, direction.counter_clockwise()), 1000),
+        ((position, direction.clockwise()), 1000),
+    ]
+    .into_iter()
+    .filter(|((next_position, _), _)| grid[*next_position] != b'#')
+}
 
 pub fn part_one(input: &str) -> Option<u32> {
     let grid = Grid::parse(input);
@@ -11,56 +30,97 @@ pub fn part_one(input: &str) -> Option<u32> {
     let start = grid.find(b'S').unwrap();
     let end = grid.find(b'E').unwrap();
 
-    // Dijkstra forwards\n
-    let mut bucket_queue = vec![Vec::new(); 1001];
+    // the reindeer starts facing east.
+    let search = dijkstra_buckets(
+        (start, Direction::East),
+        1000,
+        |&state| moves(&grid, state),
+        |&(position, _)| position == end,
+    );
 
-    let mut seen = grid.same_size_with([u32::MAX; 4]);
-    let mut cost = 0;
-    let mut lowest = u32::MAX;
+    search.goal_distance().map(|cost| cost as u32)
+}
 
-    bucket_queue[0].push((start, 0));
-    seen[start][0] = 0;
+pub fn part_two(input: &str) -> Option<u32> {
+    let grid = Grid::parse(input);
 
-    while lowest == u32::MAX {
-        let index = (cost % 1001) as usize;
+    let start = grid.find(b'S').unwrap();
+    let end = grid.find(b'E').unwrap();
 
-        while let Some((position, direction)) = bucket_queue[index].pop() {
-            if position == end {
-                lowest = cost;
-                break;
-            }
+    let search = dijkstra_buckets_all(
+        (start, Direction::East),
+        1000,
+        |&state| moves(&grid, state),
+        |&(position, _)| position == end,
+    );
+
+    let tiles: HashSet<Point> = search
+        .on_shortest_paths(search.goals().to_vec())
+        .into_iter()
+        .map(|(position, _)| position)
+        .collect();
+
+    Some(tiles.len() as u32)
+}
 
-            let left = (direction + 3) % 4;
-            let right = (direction + 1) % 4;
-
-            let next_directions = [
-                (position + DIRECTIONS[direction], direction, cost + 1),
-                (position, left, cost + 1000),
-                (position, right, cost + 1000),
-            ];
-
-            for (next_position, next_direction, next_cost) in next_directions {
-                if grid[next_position] != b'#' && next_cost < seen[next_position][next_direction] {
-                    let index = (next_cost % 1001) as usize;
-                    bucket_queue[index].push((next_position, next_direction));
-                    seen[next_position][next_direction] = next_cost;
-                }
+/// Generates a square maze of `size` (at least 5) with the start in the bottom left and the end
+/// in the top right corner. Corridors are carved with a randomized depth-first search on the odd
+/// coordinates, then some walls are knocked out so that there are loops and several routes to
+/// compare. Even sizes leave a second wall along the bottom and right border.
+pub fn generate(size: usize, rng: &mut Rng) -> String {
+    let size = size.max(5);
+    let last = if size % 2 == 1 { size - 2 } else { size - 3 };
+    let mut maze = vec![vec![b'#'; size]; size];
+
+    maze[last][1] = b'.';
+    let mut stack = vec![(1, last)];
+    while let Some(&(x, y)) = stack.last() {
+        let mut steps = [(0, -2), (0, 2), (-2, 0), (2, 0)];
+        rng.shuffle(&mut steps);
+
+        let next = steps
+            .iter()
+            .map(|&(dx, dy)| (x as isize + dx, y as isize + dy))
+            .filter(|&(x, y)| 1 <= x && x <= last as isize && 1 <= y && y <= last as isize)
+            .map(|(x, y)| (x as usize, y as usize))
+            .find(|&(x, y)| maze[y][x] == b'#');
+
+        match next {
+            Some((next_x, next_y)) => {
+                maze[(y + next_y) / 2][(x + next_x) / 2] = b'.';
+                maze[next_y][next_x] = b'.';
+                stack.push((next_x, next_y));
+            }
+            None => {
+                stack.pop();
             }
         }
+    }
 
-        cost += 1;
+    // walls with one odd and one even coordinate separate two corridors.
+    for (y, row) in maze.iter_mut().enumerate().take(last).skip(1) {
+        for (x, cell) in row.iter_mut().enumerate().take(last).skip(1) {
+            if (x + y) % 2 == 1 && rng.chance(0.1) {
+                *cell = b'.';
+            }
+        }
     }
 
-    Some(lowest)
-}
+    maze[last][1] = b'S';
+    maze[1][last] = b'E';
 
-pub fn part_two(input: &str) -> Option<u32> {
-    None
+    let mut input = String::with_capacity(size * (size + 1));
+    for row in maze {
+        input.extend(row.into_iter().map(char::from));
+        input.push('\n');
+    }
+    input
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use advent_of_code::template::generator::{assert_generator, is_square_grid};
 
     #[test]
     fn test_part_one() {
@@ -71,6 +131,25 @@ mod tests {
     #[test]
     fn test_part_two() {
         let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
+        assert_eq!(result, Some(64));
+    }
+
+    #[test]
+    fn test_generate_varies_answers() {
+        let answers: HashSet<(Option<u32>, Option<u32>)> = (0..4)
+            .map(|seed| generate(21, &mut Rng::new(seed)))
+            .map(|input| (part_one(&input), part_two(&input)))
+            .collect();
+        assert!(answers.len() > 1, "all seeds have the same answers");
+    }
+
+    #[test]
+    fn test_generate() {
+        assert_generator(generate, |input| {
+            is_square_grid(input, |c| "#.SE".contains(c))
+                && input.matches('S').count() == 1
+                && input.matches('E').count() == 1
+                && part_one(input).is_some()
+        });
     }
 }
@@ -1,4 +1,4 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
+use advent_of_code::template::commands::{all, download, generate, read, scaffold, solve, time};
 use args::{parse, AppArguments};
 
 #[cfg(feature = "today")]
@@ -7,7 +7,7 @@ use advent_of_code::template::Day;
 use std::process;
 
 mod args {
-    use advent_of_code::template::Day;
+    use advent_of_code::template::{scaling, Day};
     use std::process;
 
     pub enum AppArguments {
@@ -27,6 +27,7 @@ mod args {
             release: bool,
             dhat: bool,
             submit: Option<u8>,
+            inputs_dir: Option<String>,
         },
         All {
             release: bool,
@@ -35,11 +36,24 @@ mod args {
             all: bool,
             day: Option<Day>,
             store: bool,
+            scaling: Option<Scaling>,
+        },
+        Generate {
+            day: Day,
+            size: usize,
+            seed: u64,
+            out: Option<String>,
         },
         #[cfg(feature = "today")]
         Today,
     }
 
+    pub struct Scaling {
+        pub sizes: Vec<usize>,
+        pub seed: u64,
+        pub chart: bool,
+    }
+
     pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
         let mut args = pico_args::Arguments::from_env();
 
@@ -51,12 +65,31 @@ mod args {
                 let all = args.contains("--all");
                 let store = args.contains("--store");
 
+                let scaling = if args.contains("--scaling") {
+                    Some(Scaling {
+                        sizes: args
+                            .opt_value_from_fn("--sizes", scaling::parse_sizes)?
+                            .unwrap_or_else(|| scaling::DEFAULT_SIZES.to_vec()),
+                        seed: args.opt_value_from_str("--seed")?.unwrap_or(0),
+                        chart: args.contains("--chart"),
+                    })
+                } else {
+                    None
+                };
+
                 AppArguments::Time {
                     all,
                     day: args.opt_free_from_str()?,
                     store,
+                    scaling,
                 }
             }
+            Some("gen") => AppArguments::Generate {
+                day: args.free_from_str()?,
+                size: args.value_from_str("--size")?,
+                seed: args.opt_value_from_str("--seed")?.unwrap_or(0),
+                out: args.opt_value_from_str("--out")?,
+            },
             Some("download") => AppArguments::Download {
                 day: args.free_from_str()?,
             },
@@ -73,6 +106,7 @@ mod args {
                 release: args.contains("--release"),
                 submit: args.opt_value_from_str("--submit")?,
                 dhat: args.contains("--dhat"),
+                inputs_dir: args.opt_value_from_str("--inputs-dir")?,
             },
             #[cfg(feature = "today")]
             Some("today") => AppArguments::Today,
@@ -103,7 +137,27 @@ fn main() {
         }
         Ok(args) => match args {
             AppArguments::All { release } => all::handle(release),
-            AppArguments::Time { day, all, store } => time::handle(day, all, store),
+            AppArguments::Time {
+                day,
+                all,
+                store,
+                scaling,
+            } => match (scaling, day) {
+                (Some(scaling), Some(day)) => {
+                    time::handle_scaling(day, &scaling.sizes, scaling.seed, scaling.chart);
+                }
+                (Some(_), None) => {
+                    eprintln!("`time --scaling` requires a day, e.g. `cargo time 9 --scaling`.");
+                    std::process::exit(1);
+                }
+                (None, _) => time::handle(day, all, store),
+            },
+            AppArguments::Generate {
+                day,
+                size,
+                seed,
+                out,
+            } => generate::handle(day, size, seed, out),
             AppArguments::Download { day } => download::handle(day),
             AppArguments::Read { day } => read::handle(day),
             AppArguments::Scaffold {
@@ -121,7 +175,8 @@ fn main() {
                 release,
                 dhat,
                 submit,
-            } => solve::handle(day, release, dhat, submit),
+                inputs_dir,
+            } => solve::handle(day, release, dhat, submit, inputs_dir),
             #[cfg(feature = "today")]
             AppArguments::Today => {
                 match Day::today() {
@@ -0,0 +1,70 @@
+use std::{
+    fs,
+    path::Path,
+    process::{self, Command, Stdio},
+};
+
+use crate::template::Day;
+
+/// Path that generated inputs are written to unless an explicit output path is given.
+#[must_use]
+pub fn get_generated_path(day: Day, size: usize, seed: u64) -> String {
+    format!("data/generated/{day}-{size}-{seed}.txt")
+}
+
+/// Invokes the generator of a solution binary and returns the generated input.
+pub fn generate(day: Day, size: usize, seed: u64) -> Result<String, String> {
+    let output = Command::new("cargo")
+        .args([
+            "run",
+            "--quiet",
+            "--release",
+            "--bin",
+            &day.to_string(),
+            "--",
+            "--generate",
+            "--size",
+            &size.to_string(),
+            "--seed",
+            &seed.to_string(),
+        ])
+        .stdout(Stdio::piped())
+        .stderr(Stdio::inherit())
+        .output()
+        .map_err(|e| format!("failed to run solution for day {day}: {e}"))?;
+
+    if !output.status.success() {
+        return Err(format!("generator for day {day} exited with an error."));
+    }
+
+    String::from_utf8(output.stdout).map_err(|_| "generated input is not valid UTF-8.".into())
+}
+
+pub fn handle(day: Day, size: usize, seed: u64, out: Option<String>) {
+    let path = out.unwrap_or_else(|| get_generated_path(day, size, seed));
+
+    let input = match generate(day, size, seed) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Failed to generate input: {e}");
+            process::exit(1);
+        }
+    };
+
+    if let Some(parent) = Path::new(&path).parent() {
+        if let Err(e) = fs::create_dir_all(parent) {
+            eprintln!("Failed to create output directory: {e}");
+            process::exit(1);
+        }
+    }
+
+    match fs::write(&path, input) {
+        Ok(()) => {
+            println!("🎄 Generated input of size {size} with seed {seed} to \"{path}\".");
+        }
+        Err(e) => {
+            eprintln!("Failed to write generated input: {e}");
+            process::exit(1);
+        }
+    }
+}
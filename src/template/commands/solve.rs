@@ -1,8 +1,19 @@
-use std::process::{Command, Stdio};
+use std::process::{self, Command, Stdio};
 
 use crate::template::Day;
 
-pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
+pub fn handle(
+    day: Day,
+    release: bool,
+    dhat: bool,
+    submit_part: Option<u8>,
+    inputs_dir: Option<String>,
+) {
+    if inputs_dir.is_some() && submit_part.is_some() {
+        eprintln!("`--submit` can't be combined with `--inputs-dir`, answers are only submitted for the puzzle input.");
+        process::exit(1);
+    }
+
     let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
 
     if dhat {
@@ -18,6 +29,11 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
 
     cmd_args.push("--".to_string());
 
+    if let Some(inputs_dir) = inputs_dir {
+        cmd_args.push("--inputs-dir".to_string());
+        cmd_args.push(inputs_dir);
+    }
+
     if let Some(submit_part) = submit_part {
         cmd_args.push("--submit".to_string());
         cmd_args.push(submit_part.to_string());
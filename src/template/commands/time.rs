@@ -1,8 +1,12 @@
 use std::collections::HashSet;
+use std::path::Path;
+use std::{fs, process};
 
-use crate::template::run_multi::run_multi;
+use crate::template::commands::generate;
+use crate::template::run_multi::{child_commands, run_multi};
+use crate::template::scaling;
 use crate::template::timings::Timings;
-use crate::template::{all_days, readme_benchmarks, Day};
+use crate::template::{all_days, readme_benchmarks, Day, ANSI_BOLD, ANSI_RESET};
 
 pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
     let stored_timings = Timings::read_from_file();
@@ -38,3 +42,57 @@ pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
         }
     }
 }
+
+/// Benches a day against generated inputs of increasing size and estimates the complexity of each part.
+pub fn handle_scaling(day: Day, sizes: &[usize], seed: u64, chart: bool) {
+    let dir = format!("data/generated/scaling-{day}-{seed}");
+    let dir = Path::new(&dir);
+
+    // start from an empty directory so inputs of previous runs are not benched.
+    let _ = fs::remove_dir_all(dir);
+    if let Err(e) = fs::create_dir_all(dir) {
+        eprintln!("Failed to create directory for scaling inputs: {e}");
+        process::exit(1);
+    }
+
+    for &size in sizes {
+        println!("Generating input of size {size}...");
+        let written = generate::generate(day, size, seed).and_then(|input| {
+            fs::write(dir.join(scaling::input_file_name(size)), input).map_err(|e| e.to_string())
+        });
+        if let Err(e) = written {
+            eprintln!("Failed to generate input: {e}");
+            process::exit(1);
+        }
+    }
+
+    println!();
+    let Ok(output) = child_commands::run_solution_on_inputs_dir(day, dir) else {
+        eprintln!("Failed to run solution for day {day}.");
+        process::exit(1);
+    };
+
+    let samples = scaling::parse_samples(&output);
+
+    println!();
+    println!("{ANSI_BOLD}Estimated complexity:{ANSI_RESET}");
+    for part in 1..=2 {
+        match scaling::fit_exponent(&samples, part) {
+            Some(exponent) => println!("Part {part}: O(n^{exponent:.2})"),
+            None => println!("Part {part}: ✖"),
+        }
+    }
+
+    let csv_path = scaling::get_csv_path(day);
+    let stored = fs::create_dir_all(scaling::CSV_DIR)
+        .and_then(|()| fs::write(&csv_path, scaling::to_csv(&samples)));
+    match stored {
+        Ok(()) => println!("Stored scaling benchmarks to \"{csv_path}\"."),
+        Err(e) => eprintln!("Failed to store scaling benchmarks: {e}"),
+    }
+
+    if chart {
+        println!();
+        print!("{}", scaling::render_chart(&samples, 60, 15));
+    }
+}
@@ -0,0 +1,209 @@
+/// Support for running a solution against every input file in a directory.
+/// This is used to compare solutions across multiple personal inputs.
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::template::{ANSI_BOLD, ANSI_RESET};
+
+/// An input file found in an inputs directory.
+///
+/// Expected answers are read from files next to the input named `<stem>-<part>.answer`,
+/// e.g. `alice.txt` is paired with `alice-1.answer` and `alice-2.answer`.
+#[derive(Clone, Debug)]
+pub struct InputFile {
+    pub name: String,
+    pub content: String,
+    expected: [Option<String>; 2],
+}
+
+impl InputFile {
+    /// Returns the trimmed expected answer for a part, if an answer file exists.
+    pub fn expected(&self, part: u8) -> Option<&str> {
+        self.expected
+            .get(usize::from(part).checked_sub(1)?)?
+            .as_deref()
+    }
+}
+
+/// The outcome of running a single part against an input file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Matched,
+    Mismatched { expected: String, actual: String },
+    Unsolved { expected: Option<String> },
+    Unchecked,
+}
+
+impl Outcome {
+    /// Compares the answer of a part with the expected answer, if any.
+    pub fn compare(expected: Option<&str>, actual: Option<String>) -> Self {
+        match (expected, actual) {
+            (None, Some(_)) => Outcome::Unchecked,
+            (expected, None) => Outcome::Unsolved {
+                expected: expected.map(str::to_string),
+            },
+            (Some(expected), Some(actual)) if expected == actual.trim() => Outcome::Matched,
+            (Some(expected), Some(actual)) => Outcome::Mismatched {
+                expected: expected.to_string(),
+                actual,
+            },
+        }
+    }
+}
+
+/// Collects outcomes for all inputs and parts so they can be summarized.
+#[derive(Debug, Default)]
+pub struct Report {
+    entries: Vec<(String, u8, Outcome)>,
+}
+
+impl Report {
+    pub fn record(&mut self, input: &InputFile, part: u8, outcome: Outcome) {
+        self.entries.push((input.name.clone(), part, outcome));
+    }
+
+    /// Returns `true` if any part disagreed with its expected answer.
+    pub fn has_failures(&self) -> bool {
+        self.entries.iter().any(|(_, _, outcome)| {
+            matches!(
+                outcome,
+                Outcome::Mismatched { .. } | Outcome::Unsolved { expected: Some(_) }
+            )
+        })
+    }
+
+    pub fn print_summary(&self) {
+        let count = |f: fn(&Outcome) -> bool| self.entries.iter().filter(|(_, _, o)| f(o)).count();
+
+        let matched = count(|o| matches!(o, Outcome::Matched));
+        let unchecked =
+            count(|o| matches!(o, Outcome::Unchecked | Outcome::Unsolved { expected: None }));
+        let failed = self.entries.len() - matched - unchecked;
+
+        println!();
+        println!(
+            "{ANSI_BOLD}Summary:{ANSI_RESET} {matched} matched, {failed} disagreed, {unchecked} without expected answer."
+        );
+
+        for (name, part, outcome) in &self.entries {
+            match outcome {
+                Outcome::Mismatched { expected, actual } => {
+                    println!(" - {name} part {part}: expected {expected}, got {actual}");
+                }
+                Outcome::Unsolved {
+                    expected: Some(expected),
+                } => {
+                    println!(" - {name} part {part}: expected {expected}, got no answer");
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Returns the directory passed via `--inputs-dir`, if present.
+pub fn inputs_dir_arg() -> Option<PathBuf> {
+    let args: Vec<String> = env::args().collect();
+    let index = args.iter().position(|x| x == "--inputs-dir")?;
+
+    match args.get(index + 1) {
+        Some(dir) => Some(PathBuf::from(dir)),
+        None => {
+            eprintln!("Unexpected command-line input. Format: cargo solve 1 --inputs-dir <dir>");
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Reads all `.txt` files in a directory, sorted by name, together with their answer files.
+pub fn read_inputs_dir(dir: &Path) -> Result<Vec<InputFile>, io::Error> {
+    let mut paths: Vec<PathBuf> = fs::read_dir(dir)?
+        .map(|entry| entry.map(|e| e.path()))
+        .collect::<Result<_, _>>()?;
+
+    paths.retain(|path| path.is_file() && path.extension().is_some_and(|ext| ext == "txt"));
+    paths.sort();
+
+    paths
+        .iter()
+        .map(|path| {
+            let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+            let read_answer = |part: u8| {
+                fs::read_to_string(path.with_file_name(format!("{stem}-{part}.answer")))
+                    .ok()
+                    .map(|answer| answer.trim().to_string())
+            };
+
+            Ok(InputFile {
+                name: path
+                    .file_name()
+                    .unwrap_or_default()
+                    .to_string_lossy()
+                    .to_string(),
+                content: fs::read_to_string(path)?,
+                expected: [read_answer(1), read_answer(2)],
+            })
+        })
+        .collect()
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{InputFile, Outcome, Report};
+
+    fn input(expected: [Option<String>; 2]) -> InputFile {
+        InputFile {
+            name: "alice.txt".into(),
+            content: String::new(),
+            expected,
+        }
+    }
+
+    #[test]
+    fn reads_expected_answers_by_part() {
+        let input = input([Some("42".into()), None]);
+        assert_eq!(input.expected(1), Some("42"));
+        assert_eq!(input.expected(2), None);
+        assert_eq!(input.expected(0), None);
+        assert_eq!(input.expected(3), None);
+    }
+
+    #[test]
+    fn compares_answers() {
+        assert_eq!(
+            Outcome::compare(Some("42"), Some("42".into())),
+            Outcome::Matched
+        );
+        assert_eq!(
+            Outcome::compare(Some("42"), Some("41".into())),
+            Outcome::Mismatched {
+                expected: "42".into(),
+                actual: "41".into()
+            }
+        );
+        assert_eq!(
+            Outcome::compare(None, Some("41".into())),
+            Outcome::Unchecked
+        );
+        assert_eq!(
+            Outcome::compare(Some("42"), None),
+            Outcome::Unsolved {
+                expected: Some("42".into())
+            }
+        );
+    }
+
+    #[test]
+    fn reports_failures() {
+        let input = input([None, None]);
+        let mut report = Report::default();
+        report.record(&input, 1, Outcome::Matched);
+        report.record(&input, 2, Outcome::Unsolved { expected: None });
+        assert!(!report.has_failures());
+
+        report.record(&input, 2, Outcome::compare(Some("1"), Some("2".into())));
+        assert!(report.has_failures());
+    }
+}
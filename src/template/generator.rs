@@ -0,0 +1,111 @@
+/// Support for generating synthetic puzzle inputs from a solution binary.
+/// Days opt in by passing a generator to the `solution!` macro, e.g.
+/// `solution!(9, generator = generate)` with `pub fn generate(size: usize, rng: &mut Rng) -> String`.
+use std::{env, io::Write, process};
+
+use crate::template::Day;
+use crate::util::rng::Rng;
+
+/// Writes a synthetic input of roughly the given size, using `rng` for all randomness.
+pub type Generator = fn(usize, &mut Rng) -> String;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GenerateArgs {
+    pub size: usize,
+    pub seed: u64,
+}
+
+/// Returns the arguments passed via `--generate --size <n> [--seed <s>]`, if present.
+pub fn generate_args() -> Option<GenerateArgs> {
+    let args: Vec<String> = env::args().collect();
+    if !args.iter().any(|x| x == "--generate") {
+        return None;
+    }
+
+    let value_of = |flag: &str| {
+        let index = args.iter().position(|x| x == flag)?;
+        args.get(index + 1)
+    };
+
+    let size = value_of("--size").and_then(|x| x.parse().ok());
+    let seed = value_of("--seed").map_or(Some(0), |x| x.parse().ok());
+
+    match (size, seed) {
+        (Some(size), Some(seed)) => Some(GenerateArgs { size, seed }),
+        _ => {
+            eprintln!("Unexpected command-line input. Format: cargo gen 1 --size 100 --seed 1");
+            process::exit(1);
+        }
+    }
+}
+
+/// Checks a day's generator in tests. Inputs of a few sizes and seeds have to be reproducible
+/// from their seed, differ between seeds, and satisfy `check`, e.g. that the solution agrees
+/// with a reference implementation. Panics with the offending input otherwise.
+pub fn assert_generator(generator: Generator, check: impl Fn(&str) -> bool) {
+    for size in [5, 20] {
+        let inputs: Vec<String> = (0..4)
+            .map(|seed| {
+                let input = generator(size, &mut Rng::new(seed));
+                assert_eq!(
+                    input,
+                    generator(size, &mut Rng::new(seed)),
+                    "size {size} and seed {seed} generated different inputs"
+                );
+                assert!(
+                    check(&input),
+                    "check failed for size {size} and seed {seed}:\n{input}"
+                );
+                input
+            })
+            .collect();
+
+        assert!(
+            inputs.iter().any(|input| *input != inputs[0]),
+            "all seeds generated the same input for size {size}"
+        );
+    }
+}
+
+/// Whether `input` is a non-empty square grid of newline terminated lines, in which every cell
+/// satisfies `is_cell`. Meant for the checks passed to [`assert_generator`].
+pub fn is_square_grid(input: &str, is_cell: impl Fn(char) -> bool) -> bool {
+    let lines: Vec<&str> = input.lines().collect();
+    !lines.is_empty()
+        && input.ends_with('\n')
+        && lines
+            .iter()
+            .all(|line| line.chars().count() == lines.len() && line.chars().all(&is_cell))
+}
+
+/// Prints a generated input to stdout, or exits with an error if the day has no generator.
+pub fn run(generator: Option<Generator>, day: Day, args: GenerateArgs) {
+    let Some(generator) = generator else {
+        eprintln!("Day {day} does not have an input generator. Pass one to `solution!` with `generator = <fn>`.");
+        process::exit(1);
+    };
+
+    let mut rng = Rng::new(args.seed);
+    let input = generator(args.size, &mut rng);
+
+    let mut stdout = std::io::stdout();
+    if let Err(e) = stdout.write_all(input.as_bytes()) {
+        eprintln!("Failed to write generated input: {e}");
+        process::exit(1);
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::is_square_grid;
+
+    #[test]
+    fn checks_square_grids() {
+        assert!(is_square_grid("ab\ncd\n", |c| c.is_ascii_lowercase()));
+        assert!(!is_square_grid("ab\ncd", |c| c.is_ascii_lowercase()));
+        assert!(!is_square_grid("abc\ndef\n", |c| c.is_ascii_lowercase()));
+        assert!(!is_square_grid("ab\nc\n", |c| c.is_ascii_lowercase()));
+        assert!(!is_square_grid("ab\ncD\n", |c| c.is_ascii_lowercase()));
+        assert!(!is_square_grid("", |_| true));
+    }
+}
@@ -2,7 +2,10 @@ use std::{env, fs};
 
 pub mod aoc_cli;
 pub mod commands;
+pub mod cross_check;
+pub mod generator;
 pub mod runner;
+pub mod scaling;
 
 pub use day::*;
 
@@ -39,19 +42,45 @@ pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
 /// Creates the constant `DAY` and sets up the input and runner for each part.
 ///
 /// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
+///
+/// Parts return `Option<T>`, or a `Result` of an answer or of an optional answer, see
+/// [`runner::PartOutput`]. Errors are printed with their causes and are never submitted.
+///
+/// Alternative implementations of a part can be registered with `variants`, e.g.
+/// `solution!(11, variants = [part_one_brute_force => 1])`. Variants are run right after the
+/// canonical `part_one` / `part_two`, benched side by side with them and checked for identical
+/// answers. Variants of parts other than 1 or 2 fail to compile.
+///
+/// Days can provide an input generator with `generator`, e.g. `solution!(9, generator = generate)`.
+/// The generator is invoked by `cargo gen` to write synthetic inputs.
+///
+/// When invoked with `--inputs-dir <dir>`, every `.txt` file in that directory is solved instead
+/// of the day's input and compared against paired `<stem>-<part>.answer` files.
 #[macro_export]
 macro_rules! solution {
     ($day:expr) => {
-        $crate::solution!(@impl $day, [part_one, 1] [part_two, 2]);
+        $crate::solution!(@impl $day, [part_one, 1] [part_two, 2];);
     };
     ($day:expr, 1) => {
-        $crate::solution!(@impl $day, [part_one, 1]);
+        $crate::solution!(@impl $day, [part_one, 1];);
     };
     ($day:expr, 2) => {
-        $crate::solution!(@impl $day, [part_two, 2]);
+        $crate::solution!(@impl $day, [part_two, 2];);
+    };
+    ($day:expr, generator = $generator:path) => {
+        $crate::solution!(@impl $day, [part_one, 1] [part_two, 2]; ; $generator);
+    };
+    ($day:expr, variants = [$( $variant:ident => $variant_part:literal ),* $(,)?] $(, generator = $generator:path)?) => {
+        $crate::solution!(@impl $day, [part_one, 1] [part_two, 2]; $( [$variant, $variant_part] )*; $($generator)?);
     };
 
-    (@impl $day:expr, $( [$func:expr, $part:expr] )*) => {
+    (@generator) => { None };
+    (@generator $generator:path) => { Some($generator) };
+
+    (@impl $day:expr, $( [$func:expr, $part:expr] )*; $( [$variant:expr, $variant_part:expr] )*) => {
+        $crate::solution!(@impl $day, $( [$func, $part] )*; $( [$variant, $variant_part] )*; );
+    };
+    (@impl $day:expr, $( [$func:expr, $part:expr] )*; $( [$variant:expr, $variant_part:expr] )*; $($generator:path)?) => {
         /// The current day.
         const DAY: $crate::template::Day = $crate::day!($day);
 
@@ -61,8 +90,69 @@ macro_rules! solution {
 
         fn main() {
             use $crate::template::runner::*;
+
+            if let Some(args) = $crate::template::generator::generate_args() {
+                let generator: Option<$crate::template::generator::Generator> =
+                    $crate::solution!(@generator $($generator)?);
+                $crate::template::generator::run(generator, DAY, args);
+                return;
+            }
+
+            if let Some(dir) = $crate::template::cross_check::inputs_dir_arg() {
+                let inputs = match $crate::template::cross_check::read_inputs_dir(&dir) {
+                    Ok(inputs) => inputs,
+                    Err(e) => {
+                        eprintln!("Failed to read inputs directory {}: {e}", dir.display());
+                        std::process::exit(1);
+                    }
+                };
+
+                let mut report = $crate::template::cross_check::Report::default();
+                for (i, input) in inputs.iter().enumerate() {
+                    if i > 0 {
+                        println!();
+                    }
+                    println!("{}", input.name);
+                    println!("------");
+                    $( report.record(input, $part, check_part($func, input, $part)); )*
+                }
+
+                report.print_summary();
+                if report.has_failures() {
+                    std::process::exit(1);
+                }
+                return;
+            }
+
             let input = $crate::template::read_file("inputs", DAY);
-            $( run_part($func, &input, DAY, $part); )*
+
+            // rejects parts other than 1 and 2 when compiling, rather than when running.
+            $( const _: () = assert!(
+                $variant_part == 1 || $variant_part == 2,
+                "variants can only be registered for part 1 or 2"
+            ); )*
+            let variants: &[(u8, &dyn Fn(&PartRun) -> bool)] = &[$(
+                ($variant_part, &|canonical: &PartRun| {
+                    run_variant($variant, input.as_str(), stringify!($variant), canonical)
+                })
+            ),*];
+
+            // variants are printed right below the canonical part they are compared against.
+            let mut agreements = Vec::new();
+            $(
+                let canonical = if variants.iter().any(|(part, _)| *part == $part) {
+                    run_canonical_part($func, &input, DAY, $part, stringify!($func))
+                } else {
+                    run_part($func, &input, DAY, $part)
+                };
+                for (_, run_variant) in variants.iter().filter(|(part, _)| *part == $part) {
+                    agreements.push(run_variant(&canonical));
+                }
+            )*
+
+            if agreements.contains(&false) {
+                std::process::exit(1);
+            }
         }
     };
 }
@@ -65,12 +65,17 @@ fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String
 
     for timing in timings.data {
         let path = get_path_for_bin(timing.day);
+        let cell = |time: Option<String>, is_error: bool| match time {
+            Some(time) => time,
+            None if is_error => "error".into(),
+            None => "-".into(),
+        };
         lines.push(format!(
             "| [Day {}]({}) | `{}` | `{}` |",
             timing.day.into_inner(),
             path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
+            cell(timing.part_1, timing.part_1_error),
+            cell(timing.part_2, timing.part_2_error)
         ));
     }
 
@@ -109,18 +114,30 @@ mod tests {
                     day: day!(1),
                     part_1: Some("10ms".into()),
                     part_2: Some("20ms".into()),
+                    part_1_variant: None,
+                    part_2_variant: None,
+                    part_1_error: false,
+                    part_2_error: false,
                     total_nanos: 3e+10,
                 },
                 Timing {
                     day: day!(2),
                     part_1: Some("30ms".into()),
                     part_2: Some("40ms".into()),
+                    part_1_variant: None,
+                    part_2_variant: None,
+                    part_1_error: false,
+                    part_2_error: false,
                     total_nanos: 7e+10,
                 },
                 Timing {
                     day: day!(4),
                     part_1: Some("40ms".into()),
                     part_2: Some("50ms".into()),
+                    part_1_variant: None,
+                    part_2_variant: None,
+                    part_1_error: false,
+                    part_2_error: false,
                     total_nanos: 9e+10,
                 },
             ],
@@ -180,4 +197,15 @@ mod tests {
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn format_failed_parts() {
+        let mut timings = get_mock_timings();
+        timings.data[2].part_2 = None;
+        timings.data[2].part_2_error = true;
+
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        update_content(&mut s, timings, 190.0).unwrap();
+        assert!(s.contains("| [Day 4](./src/bin/04.rs) | `40ms` | `error` |"));
+    }
 }
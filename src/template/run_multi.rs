@@ -68,6 +68,7 @@ pub fn get_path_for_bin(day: Day) -> String {
 /// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
 pub mod child_commands {
     use super::{get_path_for_bin, Error};
+    use crate::template::runner::PART_ERROR;
     use crate::template::Day;
     use std::{
         io::{BufRead, BufReader},
@@ -96,11 +97,33 @@ pub mod child_commands {
             args.push("--time");
         }
 
+        run_forwarding_output(&args)
+    }
+
+    /// Run the solution bin for a given day in release mode against every input in `dir`, benching each.
+    pub fn run_solution_on_inputs_dir(day: Day, dir: &Path) -> Result<Vec<String>, Error> {
+        let day_padded = day.to_string();
+        let dir = dir.to_string_lossy();
+
+        run_forwarding_output(&[
+            "run",
+            "--quiet",
+            "--release",
+            "--bin",
+            &day_padded,
+            "--",
+            "--inputs-dir",
+            &dir,
+            "--time",
+        ])
+    }
+
+    fn run_forwarding_output(args: &[&str]) -> Result<Vec<String>, Error> {
         // spawn child command with piped stdout/stderr.
         // forward output to stdout/stderr while grabbing stdout lines.
 
         let mut cmd = Command::new("cargo")
-            .args(&args)
+            .args(args)
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
             .spawn()?;
@@ -133,13 +156,18 @@ pub mod child_commands {
             day,
             part_1: None,
             part_2: None,
+            part_1_variant: None,
+            part_2_variant: None,
+            part_1_error: false,
+            part_2_error: false,
             total_nanos: 0_f64,
         };
 
         output
             .iter()
             .filter_map(|l| {
-                if !l.contains(" samples)") {
+                // skip lines of variants, which are benched for comparison only.
+                if !l.contains(" samples)") || l.trim_start().starts_with('↳') {
                     return None;
                 }
 
@@ -154,16 +182,55 @@ pub mod child_commands {
             .for_each(|(part, timing_str, nanos)| {
                 if part.contains("Part 1") {
                     timings.part_1 = Some(timing_str.into());
+                    timings.part_1_variant = parse_variant(part);
                 } else if part.contains("Part 2") {
                     timings.part_2 = Some(timing_str.into());
+                    timings.part_2_variant = parse_variant(part);
                 }
 
                 timings.total_nanos += nanos;
             });
 
+        for line in output.iter().filter(|l| l.contains(PART_ERROR)) {
+            match line.split(':').next() {
+                Some(part) if part.contains("Part 1") => timings.part_1_error = true,
+                Some(part) if part.contains("Part 2") => timings.part_2_error = true,
+                _ => {}
+            }
+        }
+
         timings
     }
 
+    /// Parses the benched duration of each part in nanoseconds, ignoring variants.
+    pub fn parse_part_nanos(output: &[String]) -> [Option<f64>; 2] {
+        let mut nanos = [None, None];
+
+        for line in output {
+            if !line.contains(" samples)") || line.trim_start().starts_with('↳') {
+                continue;
+            }
+
+            let Some((_, time)) = parse_time(line) else {
+                continue;
+            };
+
+            match line.split(':').next() {
+                Some(part) if part.contains("Part 1") => nanos[0] = Some(time),
+                Some(part) if part.contains("Part 2") => nanos[1] = Some(time),
+                _ => {}
+            }
+        }
+
+        nanos
+    }
+
+    /// Parses the name of the canonical variant from a part label like `Part 1 (part_one)`.
+    fn parse_variant(part: &str) -> Option<String> {
+        let name = part.split_once('(')?.1.strip_suffix(')')?;
+        Some(name.to_string())
+    }
+
     fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
         s.split(postfix).next()?.parse().ok()
     }
@@ -174,7 +241,7 @@ pub mod child_commands {
             .split(" samples)")
             .next()?
             .split('(')
-            .last()?
+            .next_back()?
             .split('@')
             .next()?
             .trim();
@@ -205,7 +272,7 @@ pub mod child_commands {
 
     #[cfg(feature = "test_lib")]
     mod tests {
-        use super::parse_exec_time;
+        use super::{parse_exec_time, parse_part_nanos};
 
         use crate::day;
 
@@ -239,6 +306,53 @@ pub mod child_commands {
             assert_eq!(res.part_2.unwrap(), "100ms");
         }
 
+        #[test]
+        fn parses_variants() {
+            let res = parse_exec_time(
+                &[
+                    "Part 1 (part_one): 10 (1ms @ 10 samples)".into(),
+                    "  ↳ part_one_brute_force: 10 (2ms @ 10 samples) 2.00x".into(),
+                    "Part 2: 20 (3ms @ 10 samples)".into(),
+                    "".into(),
+                ],
+                day!(1),
+            );
+            assert_approx_eq!(res.total_nanos, 4000000_f64);
+            assert_eq!(res.part_1.unwrap(), "1ms");
+            assert_eq!(res.part_1_variant.unwrap(), "part_one");
+            assert_eq!(res.part_2.unwrap(), "3ms");
+            assert_eq!(res.part_2_variant, None);
+        }
+
+        #[test]
+        fn parses_part_nanos() {
+            let res = parse_part_nanos(&[
+                "Part 1 (part_one): 10 (1ms @ 10 samples)".into(),
+                "  ↳ part_one_brute_force: 10 (2ms @ 10 samples) 2.00x".into(),
+                "Part 2: ✖        ".into(),
+            ]);
+            assert_approx_eq!(res[0].unwrap(), 1000000_f64);
+            assert_eq!(res[1], None);
+        }
+
+        #[test]
+        fn parses_failed_parts() {
+            let res = parse_exec_time(
+                &[
+                    "Part 1: ✖ errorPart 1: ✖ error (1.2ms)".into(),
+                    "".into(),
+                    "  × expected a digit".into(),
+                    "Part 2: 10 (3ms @ 10 samples)".into(),
+                ],
+                day!(1),
+            );
+            assert_approx_eq!(res.total_nanos, 3000000_f64);
+            assert_eq!(res.part_1, None);
+            assert!(res.part_1_error);
+            assert_eq!(res.part_2.unwrap(), "3ms");
+            assert!(!res.part_2_error);
+        }
+
         #[test]
         fn parses_missing_parts() {
             let res = parse_exec_time(
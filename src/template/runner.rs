@@ -1,4 +1,5 @@
 /// Encapsulates code that interacts with solution functions.
+use std::error::Error;
 use std::fmt::Display;
 use std::hint::black_box;
 use std::io::{stdout, Write};
@@ -6,30 +7,218 @@ use std::process::Output;
 use std::time::{Duration, Instant};
 use std::{cmp, env, process};
 
+use miette::Report;
+
+use crate::template::cross_check::{InputFile, Outcome};
 use crate::template::ANSI_BOLD;
 use crate::template::{aoc_cli, Day, ANSI_ITALIC, ANSI_RESET};
 
-pub fn run_part<I: Clone, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
-    let part_str = format!("Part {part}");
+/// Values that solution parts can return: an optional answer, or a [`Result`] of an answer or of
+/// an optional answer. Parts that return an error are reported instead of panicking the runner.
+///
+/// The marker `M` tells apart the kinds of errors, see [`PartError`]. It is inferred from the
+/// return type of a part and never named by solutions.
+pub trait PartOutput<M> {
+    type Answer: Display;
+
+    fn into_result(self) -> miette::Result<Option<Self::Answer>>;
+}
+
+impl<T: Display> PartOutput<()> for Option<T> {
+    type Answer = T;
+
+    fn into_result(self) -> miette::Result<Option<T>> {
+        Ok(self)
+    }
+}
+
+impl<T: Display, E: PartError<M>, M> PartOutput<M> for Result<Option<T>, E> {
+    type Answer = T;
+
+    fn into_result(self) -> miette::Result<Option<T>> {
+        self.map_err(PartError::into_report)
+    }
+}
+
+// answers that are not wrapped in an option have to be listed, as `Option<T>` could implement
+// `Display` some day.
+macro_rules! plain_answers {
+    ($($t:ty)*) => ($(
+        impl<E: PartError<M>, M> PartOutput<M> for Result<$t, E> {
+            type Answer = $t;
+
+            fn into_result(self) -> miette::Result<Option<$t>> {
+                self.map(Some).map_err(PartError::into_report)
+            }
+        }
+    )*)
+}
+
+plain_answers!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize String);
+
+/// Errors that solution parts can return. [`miette::Report`]s are rendered with their labels and
+/// source code, other errors with their chain of sources, and messages as they are.
+///
+/// Errors that implement [`miette::Diagnostic`] keep their labels when converted into a report
+/// with `?` first.
+pub trait PartError<M> {
+    fn into_report(self) -> Report;
+}
+
+/// Markers for [`PartError`], so that reports, other errors and messages can be supported side
+/// by side.
+pub mod marker {
+    pub struct Report;
+    pub struct StdError;
+    pub struct Message;
+}
+
+impl PartError<marker::Report> for Report {
+    fn into_report(self) -> Report {
+        self
+    }
+}
 
+impl<E: Error + Send + Sync + 'static> PartError<marker::StdError> for E {
+    fn into_report(self) -> Report {
+        Report::from_err(self)
+    }
+}
+
+impl PartError<marker::Message> for String {
+    fn into_report(self) -> Report {
+        Report::msg(self)
+    }
+}
+
+impl PartError<marker::Message> for &'static str {
+    fn into_report(self) -> Report {
+        Report::msg(self)
+    }
+}
+
+/// Printed in place of the answer of a part that returned an error.
+pub(crate) const PART_ERROR: &str = "✖ error";
+
+/// The answer and duration of a part run, used to compare variants against the canonical solution.
+pub struct PartRun {
+    pub answer: Option<String>,
+    pub duration: Duration,
+}
+
+pub fn run_part<I: Clone, R: PartOutput<M>, M>(
+    func: impl Fn(I) -> R,
+    input: I,
+    day: Day,
+    part: u8,
+) -> PartRun {
+    run_part_labeled(func, input, day, part, &format!("Part {part}"))
+}
+
+/// Run the canonical solution of a part that has variants. The output is labeled with the name of
+/// the solution function, so `time --store` can record which variant was benched.
+pub fn run_canonical_part<I: Clone, R: PartOutput<M>, M>(
+    func: impl Fn(I) -> R,
+    input: I,
+    day: Day,
+    part: u8,
+    name: &str,
+) -> PartRun {
+    run_part_labeled(func, input, day, part, &format!("Part {part} ({name})"))
+}
+
+fn run_part_labeled<I: Clone, R: PartOutput<M>, M>(
+    func: impl Fn(I) -> R,
+    input: I,
+    day: Day,
+    part: u8,
+    label: &str,
+) -> PartRun {
     let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+        run_timed(func, input, |result| print_result(result, label, ""));
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+    print_result(&result, label, &format_duration(&duration, samples));
+
+    let answer = answer(&result);
 
-    if let Some(result) = result {
+    if let Ok(Some(result)) = result {
         submit_result(result, day, part);
     }
+
+    PartRun { answer, duration }
+}
+
+/// Run an alternative implementation of a part and compare it with the canonical run.
+/// The variant is benched alongside the canonical solution and its relative duration is printed.
+/// Returns `false` if the variant produced a different answer.
+pub fn run_variant<I: Clone, R: PartOutput<M>, M>(
+    func: impl Fn(I) -> R,
+    input: I,
+    name: &str,
+    canonical: &PartRun,
+) -> bool {
+    let label = format!("  ↳ {name}");
+
+    let (result, duration, samples) =
+        run_timed(func, input, |result| print_result(result, &label, ""));
+
+    #[allow(clippy::cast_precision_loss)]
+    let factor = duration.as_nanos() as f64 / cmp::max(canonical.duration.as_nanos(), 1) as f64;
+
+    print_result(
+        &result,
+        &label,
+        &format!("{} {factor:.2}x", format_duration(&duration, samples)),
+    );
+
+    if answer(&result) == canonical.answer {
+        return true;
+    }
+
+    let expected = canonical.answer.as_deref().unwrap_or("✖");
+    eprintln!("  ✖ {name} disagrees with the canonical answer {expected}");
+    false
+}
+
+/// Run a solution part against an input file of an inputs directory and compare the result
+/// with the file's expected answer. Results are never submitted in this mode.
+pub fn check_part<'a, R: PartOutput<M>, M>(
+    func: impl Fn(&'a str) -> R,
+    input: &'a InputFile,
+    part: u8,
+) -> Outcome {
+    let part_str = format!("Part {part}");
+
+    let (result, duration, samples) = run_timed(func, input.content.as_str(), |result| {
+        print_result(result, &part_str, "");
+    });
+
+    print_result(&result, &part_str, &format_duration(&duration, samples));
+
+    let outcome = Outcome::compare(input.expected(part), answer(&result));
+
+    match &outcome {
+        Outcome::Matched => println!("  ✔ matches expected answer"),
+        Outcome::Mismatched { expected, .. } => println!("  ✖ expected {expected}"),
+        Outcome::Unsolved {
+            expected: Some(expected),
+        } => println!("  ✖ expected {expected}"),
+        Outcome::Unsolved { expected: None } | Outcome::Unchecked => {}
+    }
+
+    outcome
 }
 
 /// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
 ///  1. in debug, the function is executed once.
 ///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
-fn run_timed<I: Clone, T>(
-    func: impl Fn(I) -> T,
+///
+/// Parts that return an error are never benched.
+fn run_timed<I: Clone, R: PartOutput<M>, M>(
+    func: impl Fn(I) -> R,
     input: I,
-    hook: impl Fn(&T),
-) -> (T, Duration, u128) {
+    hook: impl Fn(&miette::Result<Option<R::Answer>>),
+) -> (miette::Result<Option<R::Answer>>, Duration, u128) {
     let timer = Instant::now();
     let result = {
         let input = input.clone();
@@ -41,9 +230,10 @@ fn run_timed<I: Clone, T>(
     };
     let base_time = timer.elapsed();
 
+    let result = result.into_result();
     hook(&result);
 
-    let run = if std::env::args().any(|x| x == "--time") {
+    let run = if result.is_ok() && std::env::args().any(|x| x == "--time") {
         bench(func, input, &base_time)
     } else {
         (base_time, 1)
@@ -94,11 +284,25 @@ fn format_duration(duration: &Duration, samples: u128) -> String {
     }
 }
 
-fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
+/// The answer of a part run as string, errors count as no answer.
+fn answer<T: Display>(result: &miette::Result<Option<T>>) -> Option<String> {
+    result.as_ref().ok()?.as_ref().map(ToString::to_string)
+}
+
+fn print_result<T: Display>(result: &miette::Result<Option<T>>, part: &str, duration_str: &str) {
     let is_intermediate_result = duration_str.is_empty();
 
     match result {
-        Some(result) => {
+        Err(report) => {
+            if is_intermediate_result {
+                print!("{part}: {PART_ERROR}");
+            } else {
+                print!("\r");
+                println!("{part}: {PART_ERROR}{duration_str}");
+                eprintln!("{report:?}");
+            }
+        }
+        Ok(Some(result)) => {
             if result.to_string().contains('\n') {
                 let str = format!("{part}: ▼ {duration_str}");
                 if is_intermediate_result {
@@ -118,7 +322,7 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
                 }
             }
         }
-        None => {
+        Ok(None) => {
             if is_intermediate_result {
                 print!("{part}: ✖");
             } else {
@@ -167,3 +371,58 @@ fn submit_result<T: Display>(
     println!("Submitting result via aoc-cli...");
     Some(aoc_cli::submit(day, part, &result.to_string()))
 }
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::fmt;
+
+    use super::{answer, PartOutput};
+
+    fn result<R: PartOutput<M>, M>(output: R) -> Result<Option<String>, String> {
+        match output.into_result() {
+            Ok(result) => Ok(answer(&Ok(result))),
+            Err(report) => Err(format!("{report:?}")),
+        }
+    }
+
+    #[derive(Debug)]
+    struct Outer(std::num::ParseIntError);
+
+    impl fmt::Display for Outer {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "could not read the input")
+        }
+    }
+
+    impl std::error::Error for Outer {
+        fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+            Some(&self.0)
+        }
+    }
+
+    #[test]
+    fn converts_answers() {
+        assert_eq!(result(Some(1)), Ok(Some("1".into())));
+        assert_eq!(result(None::<u8>), Ok(None));
+        assert_eq!(result(Ok::<_, String>(2_u64)), Ok(Some("2".into())));
+        assert_eq!(
+            result(Ok::<_, miette::Report>(Some("a"))),
+            Ok(Some("a".into()))
+        );
+        assert_eq!(result(Ok::<Option<u8>, &str>(None)), Ok(None));
+    }
+
+    #[test]
+    fn renders_error_chains() {
+        let error = result(Err::<u32, _>(
+            "x".parse::<u32>().map_err(Outer).unwrap_err(),
+        ));
+        let error = error.unwrap_err();
+        assert!(error.contains("could not read the input"));
+        assert!(error.contains("invalid digit found in string"));
+
+        let error = result(Err::<Option<u32>, _>(miette::miette!("bad input"))).unwrap_err();
+        assert!(error.contains("bad input"));
+        assert!(result(Err::<String, _>("no answer".to_string())).is_err());
+    }
+}
@@ -0,0 +1,320 @@
+/// Scaling benchmarks measure how the runtime of a solution grows with the size of its input.
+/// Synthetic inputs of increasing size are timed and a power law `t = c * n^k` is fitted to the
+/// measurements, where the exponent `k` estimates the complexity of each part.
+use std::fmt::Write;
+
+use crate::template::run_multi::child_commands::parse_part_nanos;
+use crate::template::Day;
+
+/// Benchmark times of both parts for a single input size.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Sample {
+    pub size: usize,
+    pub part_1_nanos: Option<f64>,
+    pub part_2_nanos: Option<f64>,
+}
+
+impl Sample {
+    fn nanos(&self, part: u8) -> Option<f64> {
+        match part {
+            1 => self.part_1_nanos,
+            _ => self.part_2_nanos,
+        }
+    }
+}
+
+/// Directory that scaling benchmarks are written to, one CSV file per day.
+pub const CSV_DIR: &str = "data/scaling";
+
+#[must_use]
+pub fn get_csv_path(day: Day) -> String {
+    format!("{CSV_DIR}/{day}.csv")
+}
+
+/// Default series of input sizes when none are passed via `--sizes`.
+pub const DEFAULT_SIZES: [usize; 5] = [16, 32, 64, 128, 256];
+
+/// Parses a comma separated list of input sizes, e.g. `10,20,40`.
+pub fn parse_sizes(s: &str) -> Result<Vec<usize>, String> {
+    let sizes = s
+        .split(',')
+        .map(|x| {
+            x.trim()
+                .parse::<usize>()
+                .ok()
+                .filter(|&size| size > 0)
+                .ok_or_else(|| format!("invalid size \"{x}\""))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if sizes.len() < 2 {
+        return Err("at least two sizes are needed to estimate complexity".into());
+    }
+
+    Ok(sizes)
+}
+
+/// Name of the input file for a size, which `parse_samples` maps back to the size.
+pub fn input_file_name(size: usize) -> String {
+    format!("{size}.txt")
+}
+
+/// Parses the output of an `--inputs-dir` run over scaling inputs into samples, sorted by size.
+/// Each input's output starts with its file name, followed by a `------` line.
+pub fn parse_samples(output: &[String]) -> Vec<Sample> {
+    let mut sections: Vec<(usize, usize)> = output
+        .windows(2)
+        .enumerate()
+        .filter(|(_, lines)| lines[1] == "------")
+        .filter_map(|(i, lines)| Some((lines[0].strip_suffix(".txt")?.parse().ok()?, i + 2)))
+        .collect();
+    sections.sort_unstable();
+
+    let starts: Vec<usize> = sections.iter().map(|&(_, start)| start).collect();
+
+    sections
+        .iter()
+        .map(|&(size, start)| {
+            // a section ends at the header of the next section in the output.
+            let end = starts
+                .iter()
+                .filter(|&&other| other > start)
+                .min()
+                .map_or(output.len(), |other| other - 2);
+            let [part_1_nanos, part_2_nanos] = parse_part_nanos(&output[start..end]);
+
+            Sample {
+                size,
+                part_1_nanos,
+                part_2_nanos,
+            }
+        })
+        .collect()
+}
+
+/// Estimates the exponent `k` of `t = c * n^k` for a part by a least squares fit in log-log space.
+/// Returns `None` if fewer than two distinct sizes have a timing.
+#[allow(clippy::cast_precision_loss)]
+pub fn fit_exponent(samples: &[Sample], part: u8) -> Option<f64> {
+    let points: Vec<(f64, f64)> = samples
+        .iter()
+        .filter_map(|s| Some(((s.size as f64).ln(), s.nanos(part)?.max(1.0).ln())))
+        .collect();
+
+    if points.len() < 2 {
+        return None;
+    }
+
+    let n = points.len() as f64;
+    let mean_x = points.iter().map(|p| p.0).sum::<f64>() / n;
+    let mean_y = points.iter().map(|p| p.1).sum::<f64>() / n;
+
+    let variance: f64 = points.iter().map(|p| (p.0 - mean_x).powi(2)).sum();
+    if variance == 0.0 {
+        return None;
+    }
+
+    let covariance: f64 = points.iter().map(|p| (p.0 - mean_x) * (p.1 - mean_y)).sum();
+
+    Some(covariance / variance)
+}
+
+/// Serializes samples to CSV with a header row. Missing timings are left empty.
+pub fn to_csv(samples: &[Sample]) -> String {
+    let mut csv = String::from("size,part_1_nanos,part_2_nanos\n");
+
+    for sample in samples {
+        let format = |nanos: Option<f64>| nanos.map(|x| format!("{x:.0}")).unwrap_or_default();
+        let _ = writeln!(
+            csv,
+            "{},{},{}",
+            sample.size,
+            format(sample.part_1_nanos),
+            format(sample.part_2_nanos)
+        );
+    }
+
+    csv
+}
+
+/// Renders a log-log scatter plot of the samples. Part 1 is plotted as `1`, part 2 as `2` and
+/// points shared by both parts as `*`.
+#[allow(
+    clippy::cast_precision_loss,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)]
+pub fn render_chart(samples: &[Sample], width: usize, height: usize) -> String {
+    let points: Vec<(f64, f64, char)> = samples
+        .iter()
+        .flat_map(|s| {
+            [(1, '1'), (2, '2')]
+                .into_iter()
+                .filter_map(|(part, marker)| {
+                    Some(((s.size as f64).ln(), s.nanos(part)?.max(1.0).ln(), marker))
+                })
+        })
+        .collect();
+
+    if points.is_empty() || width < 2 || height < 2 {
+        return String::from("No timings to plot.\n");
+    }
+
+    let bounds = |f: fn(&(f64, f64, char)) -> f64| {
+        points
+            .iter()
+            .map(f)
+            .fold((f64::MAX, f64::MIN), |(lo, hi), v| (lo.min(v), hi.max(v)))
+    };
+    let (min_x, max_x) = bounds(|p| p.0);
+    let (min_y, max_y) = bounds(|p| p.1);
+
+    let scale = |v: f64, lo: f64, hi: f64, steps: usize| {
+        if hi > lo {
+            ((v - lo) / (hi - lo) * (steps - 1) as f64).round() as usize
+        } else {
+            0
+        }
+    };
+
+    let mut canvas = vec![vec![' '; width]; height];
+    for &(x, y, marker) in &points {
+        let col = scale(x, min_x, max_x, width);
+        let row = height - 1 - scale(y, min_y, max_y, height);
+        let cell = &mut canvas[row][col];
+        *cell = if *cell == ' ' || *cell == marker {
+            marker
+        } else {
+            '*'
+        };
+    }
+
+    let top = format_nanos(max_y.exp());
+    let bottom = format_nanos(min_y.exp());
+    let label_width = top.len().max(bottom.len());
+
+    let mut chart = String::new();
+    for (i, row) in canvas.iter().enumerate() {
+        let label = match i {
+            0 => top.as_str(),
+            i if i == height - 1 => bottom.as_str(),
+            _ => "",
+        };
+        let line: String = row.iter().collect();
+        let _ = writeln!(chart, "{label:>label_width$} │{}", line.trim_end());
+    }
+
+    let min_size = format!("n={}", min_x.exp().round());
+    let max_size = format!("n={}", max_x.exp().round());
+    let _ = writeln!(chart, "{:>label_width$} └{}", "", "─".repeat(width));
+    let _ = writeln!(
+        chart,
+        "{:>label_width$}  {min_size}{max_size:>gap$}",
+        "",
+        gap = width.saturating_sub(min_size.len())
+    );
+
+    chart
+}
+
+fn format_nanos(nanos: f64) -> String {
+    format!(
+        "{:.1?}",
+        std::time::Duration::from_nanos(nanos.round() as u64)
+    )
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{fit_exponent, parse_samples, parse_sizes, render_chart, to_csv, Sample};
+
+    fn samples(f: impl Fn(f64) -> f64) -> Vec<Sample> {
+        [10, 20, 40, 80]
+            .into_iter()
+            .map(|size| Sample {
+                size,
+                part_1_nanos: Some(f(size as f64)),
+                part_2_nanos: None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn fits_exponents() {
+        let linear = fit_exponent(&samples(|n| 3.0 * n), 1).unwrap();
+        assert!((linear - 1.0).abs() < 1e-9);
+
+        let quadratic = fit_exponent(&samples(|n| 0.5 * n * n), 1).unwrap();
+        assert!((quadratic - 2.0).abs() < 1e-9);
+
+        assert_eq!(fit_exponent(&samples(|n| n), 2), None);
+    }
+
+    #[test]
+    fn parses_sizes() {
+        assert_eq!(parse_sizes("10, 20,40"), Ok(vec![10, 20, 40]));
+        assert!(parse_sizes("10").is_err());
+        assert!(parse_sizes("10,0").is_err());
+        assert!(parse_sizes("10,x").is_err());
+    }
+
+    #[test]
+    fn parses_samples_from_output() {
+        let output: Vec<String> = [
+            "20.txt",
+            "------",
+            "Part 1: 1 (4ms @ 10 samples)",
+            "Part 2: ✖        ",
+            "",
+            "10.txt",
+            "------",
+            "Part 1: 1 (1ms @ 10 samples)",
+            "Part 2: 2 (2ms @ 10 samples)",
+            "",
+            "Summary: 0 matched, 0 disagreed, 4 without expected answer.",
+        ]
+        .map(String::from)
+        .to_vec();
+
+        let samples = parse_samples(&output);
+        assert_eq!(
+            samples,
+            vec![
+                Sample {
+                    size: 10,
+                    part_1_nanos: Some(1_000_000.0),
+                    part_2_nanos: Some(2_000_000.0),
+                },
+                Sample {
+                    size: 20,
+                    part_1_nanos: Some(4_000_000.0),
+                    part_2_nanos: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn writes_csv() {
+        let samples = vec![Sample {
+            size: 10,
+            part_1_nanos: Some(1500.4),
+            part_2_nanos: None,
+        }];
+        assert_eq!(
+            to_csv(&samples),
+            "size,part_1_nanos,part_2_nanos\n10,1500,\n"
+        );
+    }
+
+    #[test]
+    fn renders_chart() {
+        let chart = render_chart(&samples(|n| n * 1000.0), 10, 4);
+        let lines: Vec<&str> = chart.lines().collect();
+        assert_eq!(lines.len(), 6);
+        assert!(lines[0].ends_with("│         1"));
+        assert!(lines[3].ends_with("│1"));
+        assert!(lines[5].contains("n=10"));
+        assert!(lines[5].ends_with("n=80"));
+    }
+}
@@ -11,6 +11,14 @@ pub struct Timing {
     pub day: Day,
     pub part_1: Option<String>,
     pub part_2: Option<String>,
+    /// Name of the canonical solution function, if part 1 has variants.
+    pub part_1_variant: Option<String>,
+    /// Name of the canonical solution function, if part 2 has variants.
+    pub part_2_variant: Option<String>,
+    /// Whether part 1 returned an error instead of an answer.
+    pub part_1_error: bool,
+    /// Whether part 2 returned an error instead of an answer.
+    pub part_2_error: bool,
     pub total_nanos: f64,
 }
 
@@ -51,7 +59,7 @@ impl Timings {
             }
         }
 
-        data.sort_unstable_by(|a, b| a.day.cmp(&b.day));
+        data.sort_unstable_by_key(|a| a.day);
         Timings { data }
     }
 
@@ -133,6 +141,22 @@ impl From<&Timing> for JsonValue {
             },
         );
 
+        if let Some(variant) = &value.part_1_variant {
+            map.insert("part_1_variant".into(), JsonValue::String(variant.clone()));
+        }
+
+        if let Some(variant) = &value.part_2_variant {
+            map.insert("part_2_variant".into(), JsonValue::String(variant.clone()));
+        }
+
+        if value.part_1_error {
+            map.insert("part_1_status".into(), JsonValue::String("error".into()));
+        }
+
+        if value.part_2_error {
+            map.insert("part_2_status".into(), JsonValue::String("error".into()));
+        }
+
         JsonValue::Object(map)
     }
 }
@@ -161,6 +185,17 @@ impl TryFrom<&JsonValue> for Timing {
             .map(|v| if v.is_null() { None } else { v.get::<String>() })
             .ok_or("Expected timing.part_2 to be null or string.")?;
 
+        // variants are optional to stay compatible with timings stored before they existed.
+        let part_1_variant = json.get("part_1_variant").and_then(|v| v.get::<String>());
+        let part_2_variant = json.get("part_2_variant").and_then(|v| v.get::<String>());
+
+        // as are statuses, which are only stored for parts that returned an error.
+        let is_error = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<String>())
+                .is_some_and(|status| status == "error")
+        };
+
         let total_nanos = json
             .get("total_nanos")
             .and_then(|v| v.get::<f64>().copied())
@@ -170,6 +205,10 @@ impl TryFrom<&JsonValue> for Timing {
             day,
             part_1: part_1.cloned(),
             part_2: part_2.cloned(),
+            part_1_variant: part_1_variant.cloned(),
+            part_2_variant: part_2_variant.cloned(),
+            part_1_error: is_error("part_1_status"),
+            part_2_error: is_error("part_2_status"),
             total_nanos,
         })
     }
@@ -190,18 +229,30 @@ mod tests {
                     day: day!(1),
                     part_1: Some("10ms".into()),
                     part_2: Some("20ms".into()),
+                    part_1_variant: None,
+                    part_2_variant: None,
+                    part_1_error: false,
+                    part_2_error: false,
                     total_nanos: 3e+10,
                 },
                 Timing {
                     day: day!(2),
                     part_1: Some("30ms".into()),
                     part_2: Some("40ms".into()),
+                    part_1_variant: None,
+                    part_2_variant: None,
+                    part_1_error: false,
+                    part_2_error: false,
                     total_nanos: 7e+10,
                 },
                 Timing {
                     day: day!(4),
                     part_1: Some("40ms".into()),
                     part_2: None,
+                    part_1_variant: None,
+                    part_2_variant: None,
+                    part_1_error: false,
+                    part_2_error: false,
                     total_nanos: 4e+10,
                 },
             ],
@@ -223,6 +274,24 @@ mod tests {
             assert_eq!(timing.total_nanos, 1_000_000_000_f64);
         }
 
+        #[test]
+        fn handles_json_timings_with_variants() {
+            let json = r#"{ "data": [{ "day": "11", "part_1": "1ms", "part_2": "2ms", "part_1_variant": "part_one", "total_nanos": 3000000 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            assert_eq!(timing.part_1_variant, Some("part_one".to_string()));
+            assert_eq!(timing.part_2_variant, None);
+        }
+
+        #[test]
+        fn handles_json_timings_with_errors() {
+            let json = r#"{ "data": [{ "day": "05", "part_1": null, "part_2": "2ms", "part_1_status": "error", "total_nanos": 2000000 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            assert!(timing.part_1_error);
+            assert!(!timing.part_2_error);
+        }
+
         #[test]
         fn handles_empty_timings() {
             let json = r#"{ "data": [] }"#.to_string();
@@ -247,6 +316,7 @@ mod tests {
 
     mod serialization {
         use super::get_mock_timings;
+        use crate::template::timings::Timings;
         use std::collections::HashMap;
         use tinyjson::JsonValue;
 
@@ -266,6 +336,17 @@ mod tests {
                 3
             );
         }
+
+        #[test]
+        fn serializes_error_status() {
+            let mut timings = get_mock_timings();
+            timings.data[2].part_2_error = true;
+
+            let json = JsonValue::from(timings).stringify().unwrap();
+            let timings = Timings::try_from(json).unwrap();
+            assert!(timings.data[2].part_2_error);
+            assert!(!timings.data[1].part_2_error);
+        }
     }
 
     mod is_day_complete {
@@ -281,11 +362,15 @@ mod tests {
                     day: day!(1),
                     part_1: Some("1ms".into()),
                     part_2: Some("2ms".into()),
+                    part_1_variant: None,
+                    part_2_variant: None,
+                    part_1_error: false,
+                    part_2_error: false,
                     total_nanos: 3_000_000_000_f64,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), true);
+            assert_eq!(timings.is_day_complete(day!(1)), true);
         }
 
         #[test]
@@ -295,11 +380,15 @@ mod tests {
                     day: day!(1),
                     part_1: Some("1ms".into()),
                     part_2: None,
+                    part_1_variant: None,
+                    part_2_variant: None,
+                    part_1_error: false,
+                    part_2_error: false,
                     total_nanos: 1_000_000_000_f64,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
         }
 
         #[test]
@@ -309,11 +398,15 @@ mod tests {
                     day: day!(1),
                     part_1: None,
                     part_2: None,
+                    part_1_variant: None,
+                    part_2_variant: None,
+                    part_1_error: false,
+                    part_2_error: false,
                     total_nanos: 0.0,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
         }
     }
 
@@ -333,6 +426,10 @@ mod tests {
                     day: day!(3),
                     part_1: None,
                     part_2: None,
+                    part_1_variant: None,
+                    part_2_variant: None,
+                    part_1_error: false,
+                    part_2_error: false,
                     total_nanos: 0_f64,
                 }],
             };
@@ -353,6 +450,10 @@ mod tests {
                     day: day!(2),
                     part_1: None,
                     part_2: None,
+                    part_1_variant: None,
+                    part_2_variant: None,
+                    part_1_error: false,
+                    part_2_error: false,
                     total_nanos: 0_f64,
                 }],
             };
@@ -365,6 +466,47 @@ mod tests {
             assert_eq!(merged.data[2].day, day!(4));
         }
 
+        #[test]
+        fn overwrites_errored_parts_with_later_answers() {
+            let mut timings = get_mock_timings();
+            timings.data[1].part_1 = None;
+            timings.data[1].part_1_error = true;
+
+            let other = Timings {
+                data: vec![Timing {
+                    part_1: Some("1ms".into()),
+                    part_1_error: false,
+                    ..get_mock_timings().data[1].clone()
+                }],
+            };
+            let merged = timings.merge(&other);
+
+            assert_eq!(merged.data.len(), 3);
+            assert_eq!(merged.data[1].day, day!(2));
+            assert_eq!(merged.data[1].part_1, Some("1ms".into()));
+            assert!(!merged.data[1].part_1_error);
+        }
+
+        #[test]
+        fn overwrites_answers_with_later_errors() {
+            let timings = get_mock_timings();
+
+            let other = Timings {
+                data: vec![Timing {
+                    part_2: None,
+                    part_2_error: true,
+                    ..get_mock_timings().data[1].clone()
+                }],
+            };
+            let merged = timings.merge(&other);
+
+            assert_eq!(merged.data.len(), 3);
+            assert_eq!(merged.data[1].day, day!(2));
+            assert_eq!(merged.data[1].part_2, None);
+            assert!(merged.data[1].part_2_error);
+            assert!(!merged.data[1].part_1_error);
+        }
+
         #[test]
         fn handles_empty_timings() {
             let timings = Timings::default();
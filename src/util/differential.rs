@@ -0,0 +1,374 @@
+//! Differential testing of two implementations against many generated inputs.
+//!
+//! Fixed examples rarely cover the edge cases of a puzzle. This module runs a fast implementation
+//! and a simple reference implementation (e.g. a brute force) over many inputs produced by a
+//! generator and reports the first input on which they disagree. The failing input is then
+//! [shrunk] to a minimal case that still triggers the disagreement.
+//!
+//! ```
+//!   # use advent_of_code::util::differential::assert_equivalent;
+//!
+//!   assert_equivalent(
+//!       |rng| (0..rng.index(10)).map(|_| rng.range(-100..100)).collect::<Vec<_>>(),
+//!       |numbers| numbers.iter().sum::<i64>(),
+//!       |numbers| numbers.iter().fold(0, |acc, x| acc + x),
+//!   );
+//! ```
+//!
+//! Shrunk inputs are derived from the [`Shrink`] implementation of the input type, so generators
+//! should produce structured values (e.g. a `Vec` of numbers) rather than raw puzzle text. Both
+//! implementations need to accept every shrunk candidate without panicking.
+//!
+//! [shrunk]: Shrink
+
+use std::fmt::{self, Debug};
+
+use crate::util::rng::Rng;
+
+/// Types that can produce smaller candidates of themselves, used to minimize failing inputs.
+pub trait Shrink: Sized {
+    /// Returns candidates that are strictly "smaller" than `self`, most aggressive first.
+    fn shrink(&self) -> Vec<Self>;
+}
+
+macro_rules! shrink_signed {
+    ($($t:ty)*) => ($(
+        impl Shrink for $t {
+            fn shrink(&self) -> Vec<Self> {
+                let mut candidates = Vec::new();
+                if *self != 0 {
+                    candidates.push(0);
+                }
+                if let Some(positive) = self.checked_neg().filter(|x| *x > 0) {
+                    candidates.push(positive);
+                }
+                let half = *self / 2;
+                if half != 0 {
+                    candidates.push(half);
+                }
+                let closer = *self - self.signum();
+                if closer != 0 && closer != half {
+                    candidates.push(closer);
+                }
+                candidates
+            }
+        }
+    )*)
+}
+
+macro_rules! shrink_unsigned {
+    ($($t:ty)*) => ($(
+        impl Shrink for $t {
+            fn shrink(&self) -> Vec<Self> {
+                let mut candidates = Vec::new();
+                if *self != 0 {
+                    candidates.push(0);
+                }
+                let half = *self / 2;
+                if half != 0 {
+                    candidates.push(half);
+                }
+                if *self > 1 && *self - 1 != half {
+                    candidates.push(*self - 1);
+                }
+                candidates
+            }
+        }
+    )*)
+}
+
+shrink_signed!(i8 i16 i32 i64 i128 isize);
+shrink_unsigned!(u8 u16 u32 u64 u128 usize);
+
+impl Shrink for bool {
+    fn shrink(&self) -> Vec<Self> {
+        if *self {
+            vec![false]
+        } else {
+            vec![]
+        }
+    }
+}
+
+impl<T: Shrink + Clone> Shrink for Vec<T> {
+    fn shrink(&self) -> Vec<Self> {
+        let mut candidates = Vec::new();
+
+        // remove chunks of decreasing size, starting with halves.
+        let mut chunk = self.len() / 2;
+        while chunk > 0 {
+            for start in (0..self.len()).step_by(chunk) {
+                let mut candidate = self.clone();
+                candidate.drain(start..(start + chunk).min(self.len()));
+                candidates.push(candidate);
+            }
+            chunk /= 2;
+        }
+
+        if self.len() == 1 {
+            candidates.push(vec![]);
+        }
+
+        // then shrink individual elements.
+        for (i, value) in self.iter().enumerate() {
+            for smaller in value.shrink() {
+                let mut candidate = self.clone();
+                candidate[i] = smaller;
+                candidates.push(candidate);
+            }
+        }
+
+        candidates
+    }
+}
+
+impl Shrink for String {
+    /// Removes whole lines, keeping line based puzzle inputs well-formed.
+    fn shrink(&self) -> Vec<Self> {
+        let lines: Vec<String> = self.lines().map(str::to_string).collect();
+        if lines.len() <= 1 {
+            return vec![];
+        }
+
+        let mut candidates = Vec::new();
+        let mut chunk = lines.len() / 2;
+        while chunk > 0 {
+            for start in (0..lines.len()).step_by(chunk) {
+                let mut candidate = lines.clone();
+                candidate.drain(start..(start + chunk).min(lines.len()));
+                if !candidate.is_empty() {
+                    candidates.push(candidate.join("\n"));
+                }
+            }
+            chunk /= 2;
+        }
+        candidates
+    }
+}
+
+impl<A: Shrink + Clone, B: Shrink + Clone> Shrink for (A, B) {
+    fn shrink(&self) -> Vec<Self> {
+        let mut candidates: Vec<Self> = self
+            .0
+            .shrink()
+            .into_iter()
+            .map(|a| (a, self.1.clone()))
+            .collect();
+        candidates.extend(self.1.shrink().into_iter().map(|b| (self.0.clone(), b)));
+        candidates
+    }
+}
+
+impl<A: Shrink + Clone, B: Shrink + Clone, C: Shrink + Clone> Shrink for (A, B, C) {
+    fn shrink(&self) -> Vec<Self> {
+        let (a, b, c) = self;
+        let mut candidates: Vec<Self> = a
+            .shrink()
+            .into_iter()
+            .map(|a| (a, b.clone(), c.clone()))
+            .collect();
+        candidates.extend(b.shrink().into_iter().map(|b| (a.clone(), b, c.clone())));
+        candidates.extend(c.shrink().into_iter().map(|c| (a.clone(), b.clone(), c)));
+        candidates
+    }
+}
+
+/// Settings for a differential test run.
+#[derive(Clone, Copy, Debug)]
+pub struct Config {
+    /// Number of generated inputs to check.
+    pub cases: usize,
+    /// Seed of the random number generator passed to the input generator.
+    pub seed: u64,
+    /// Upper bound of accepted shrink steps, to guarantee termination.
+    pub max_shrink_steps: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            cases: 256,
+            seed: 0x00c0_ffee,
+            max_shrink_steps: 1000,
+        }
+    }
+}
+
+/// An input on which two implementations disagree.
+pub struct Failure<I, T> {
+    /// Index of the generated case that failed first.
+    pub case: usize,
+    /// The generated input before shrinking.
+    pub original: I,
+    /// The minimal input found that still makes the implementations disagree.
+    pub input: I,
+    /// Result of the reference implementation for `input`.
+    pub expected: T,
+    /// Result of the candidate implementation for `input`.
+    pub actual: T,
+    /// Number of accepted shrink steps.
+    pub shrink_steps: usize,
+}
+
+impl<I: Debug, T: Debug> Debug for Failure<I, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "implementations disagree on case {} (shrunk {} times)",
+            self.case, self.shrink_steps
+        )?;
+        writeln!(f, "  input:    {:?}", self.input)?;
+        writeln!(f, "  expected: {:?}", self.expected)?;
+        writeln!(f, "  actual:   {:?}", self.actual)?;
+        write!(f, "  original: {:?}", self.original)
+    }
+}
+
+/// Checks `candidate` against `reference` for inputs produced by `generate`, using the default
+/// [`Config`]. Returns the shrunk failing input if the two implementations disagree.
+pub fn check<I, T>(
+    generate: impl FnMut(&mut Rng) -> I,
+    reference: impl Fn(&I) -> T,
+    candidate: impl Fn(&I) -> T,
+) -> Result<(), Failure<I, T>>
+where
+    I: Shrink + Clone,
+    T: PartialEq,
+{
+    check_with(Config::default(), generate, reference, candidate)
+}
+
+/// Like [`check`], with explicit settings.
+pub fn check_with<I, T>(
+    config: Config,
+    mut generate: impl FnMut(&mut Rng) -> I,
+    reference: impl Fn(&I) -> T,
+    candidate: impl Fn(&I) -> T,
+) -> Result<(), Failure<I, T>>
+where
+    I: Shrink + Clone,
+    T: PartialEq,
+{
+    let mut rng = Rng::new(config.seed);
+
+    for case in 0..config.cases {
+        let original = generate(&mut rng);
+        let expected = reference(&original);
+        let actual = candidate(&original);
+
+        if expected != actual {
+            let mut failure = Failure {
+                case,
+                input: original.clone(),
+                original,
+                expected,
+                actual,
+                shrink_steps: 0,
+            };
+            shrink_failure(
+                &mut failure,
+                config.max_shrink_steps,
+                &reference,
+                &candidate,
+            );
+            return Err(failure);
+        }
+    }
+
+    Ok(())
+}
+
+/// Like [`check`], but panics with the shrunk failing input. Meant to be used in tests.
+pub fn assert_equivalent<I, T>(
+    generate: impl FnMut(&mut Rng) -> I,
+    reference: impl Fn(&I) -> T,
+    candidate: impl Fn(&I) -> T,
+) where
+    I: Shrink + Clone + Debug,
+    T: PartialEq + Debug,
+{
+    if let Err(failure) = check(generate, reference, candidate) {
+        panic!("{failure:?}");
+    }
+}
+
+/// Greedily replaces the failing input by the first smaller candidate that still fails,
+/// until no candidate fails anymore.
+fn shrink_failure<I, T>(
+    failure: &mut Failure<I, T>,
+    max_steps: usize,
+    reference: impl Fn(&I) -> T,
+    candidate: impl Fn(&I) -> T,
+) where
+    I: Shrink + Clone,
+    T: PartialEq,
+{
+    'outer: while failure.shrink_steps < max_steps {
+        for smaller in failure.input.shrink() {
+            let expected = reference(&smaller);
+            let actual = candidate(&smaller);
+
+            if expected != actual {
+                failure.input = smaller;
+                failure.expected = expected;
+                failure.actual = actual;
+                failure.shrink_steps += 1;
+                continue 'outer;
+            }
+        }
+
+        break;
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{check, Shrink};
+
+    #[test]
+    fn accepts_equivalent_implementations() {
+        let result = check(
+            |rng| {
+                (0..rng.index(10))
+                    .map(|_| rng.range(-50..50))
+                    .collect::<Vec<_>>()
+            },
+            |numbers| numbers.iter().sum::<i64>(),
+            |numbers| numbers.iter().rev().sum::<i64>(),
+        );
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn shrinks_to_minimal_input() {
+        // the candidate is wrong as soon as a number above 10 is present.
+        let failure = check(
+            |rng| {
+                (0..rng.index(20))
+                    .map(|_| rng.range(0..100))
+                    .collect::<Vec<_>>()
+            },
+            |numbers| numbers.iter().sum::<i64>(),
+            |numbers| numbers.iter().map(|&x| x.min(10)).sum::<i64>(),
+        )
+        .unwrap_err();
+
+        assert_eq!(failure.input, vec![11]);
+        assert_eq!(failure.expected, 11);
+        assert_eq!(failure.actual, 10);
+    }
+
+    #[test]
+    fn shrinks_integers_towards_zero() {
+        assert_eq!(10_u32.shrink(), vec![0, 5, 9]);
+        assert_eq!((-10_i32).shrink(), vec![0, 10, -5, -9]);
+        assert!(0_i64.shrink().is_empty());
+    }
+
+    #[test]
+    fn shrinks_strings_by_lines() {
+        let input = "a\nb".to_string();
+        assert_eq!(input.shrink(), vec!["b".to_string(), "a".to_string()]);
+        assert!("a".to_string().shrink().is_empty());
+    }
+}
@@ -0,0 +1,299 @@
+//! Lines, bounding boxes and polygon areas on the integer lattice.
+//!
+//! [`lattice_line`] visits every lattice point on a segment, which is what most "points in line
+//! with" puzzles need, while [`bresenham`] approximates a segment with a connected line of
+//! pixels:
+//!
+//! ```
+//!   # use advent_of_code::util::geometry::{bresenham, lattice_line};
+//!   # use advent_of_code::util::point::Point;
+//!
+//!   let (from, to) = (Point::new(0, 0), Point::new(4, 2));
+//!   assert_eq!(lattice_line(from, to).count(), 3);
+//!   assert_eq!(bresenham(from, to).count(), 5);
+//! ```
+//!
+//! The area of a polygon with lattice vertices follows from the shoelace formula, and the
+//! number of lattice points inside of it from Pick's theorem:
+//!
+//! ```
+//!   # use advent_of_code::util::geometry::{double_area, interior_points};
+//!   # use advent_of_code::util::point::Point;
+//!
+//!   let square = [Point::new(0, 0), Point::new(4, 0), Point::new(4, 4), Point::new(0, 4)];
+//!   assert_eq!(double_area(&square), 32);
+//!   assert_eq!(interior_points(&square), 9);
+//! ```
+
+use std::hash::{Hash, Hasher};
+
+use crate::util::integer::Signed;
+use crate::util::point::Point;
+
+/// Points of the segment from `from` to `to`, both inclusive, such that consecutive points
+/// touch orthogonally or diagonally.
+pub fn bresenham<T: Signed>(from: Point<T>, to: Point<T>) -> impl Iterator<Item = Point<T>> {
+    let (dx, dy) = ((to.x - from.x).abs(), -(to.y - from.y).abs());
+    let (sx, sy) = ((to.x - from.x).signum(), (to.y - from.y).signum());
+    let mut error = dx + dy;
+    let mut next = Some(from);
+
+    std::iter::from_fn(move || {
+        let point = next?;
+        next = (point != to).then(|| {
+            let mut step = point;
+            let double = error + error;
+            if double >= dy {
+                error += dy;
+                step.x += sx;
+            }
+            if double <= dx {
+                error += dx;
+                step.y += sy;
+            }
+            step
+        });
+        Some(point)
+    })
+}
+
+/// All lattice points on the segment from `from` to `to`, both inclusive, stepping by the
+/// [reduced](Point::reduce) difference of the two points.
+pub fn lattice_line<T: Signed>(from: Point<T>, to: Point<T>) -> impl Iterator<Item = Point<T>> {
+    let step = (to - from).reduce();
+    let mut next = Some(from);
+
+    std::iter::from_fn(move || {
+        let point = next?;
+        next = (point != to).then(|| point + step);
+        Some(point)
+    })
+}
+
+/// Axis-aligned rectangle, where both corners are inclusive.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct BoundingBox<T = i32> {
+    pub min: Point<T>,
+    pub max: Point<T>,
+}
+
+impl<T: Signed> BoundingBox<T> {
+    /// The smallest box containing both corners, which can be given in any order.
+    pub fn new(a: Point<T>, b: Point<T>) -> Self {
+        BoundingBox {
+            min: Point::new(a.x.min(b.x), a.y.min(b.y)),
+            max: Point::new(a.x.max(b.x), a.y.max(b.y)),
+        }
+    }
+
+    /// The smallest box containing all points, or `None` if there are no points.
+    pub fn from_points(points: impl IntoIterator<Item = Point<T>>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        let mut bounds = BoundingBox::new(first, first);
+        points.for_each(|point| bounds.extend(point));
+        Some(bounds)
+    }
+
+    #[inline]
+    pub fn width(&self) -> T {
+        self.max.x - self.min.x + T::ONE
+    }
+
+    #[inline]
+    pub fn height(&self) -> T {
+        self.max.y - self.min.y + T::ONE
+    }
+
+    #[inline]
+    pub fn contains(&self, point: Point<T>) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x) && (self.min.y..=self.max.y).contains(&point.y)
+    }
+
+    /// Grows the box to contain `point`.
+    pub fn extend(&mut self, point: Point<T>) {
+        *self = self.union(&BoundingBox::new(point, point));
+    }
+
+    /// The smallest box containing both boxes.
+    pub fn union(&self, other: &Self) -> Self {
+        BoundingBox {
+            min: Point::new(self.min.x.min(other.min.x), self.min.y.min(other.min.y)),
+            max: Point::new(self.max.x.max(other.max.x), self.max.y.max(other.max.y)),
+        }
+    }
+
+    /// The box contained in both boxes, or `None` if they do not overlap.
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let min = Point::new(self.min.x.max(other.min.x), self.min.y.max(other.min.y));
+        let max = Point::new(self.max.x.min(other.max.x), self.max.y.min(other.max.y));
+        (min.x <= max.x && min.y <= max.y).then_some(BoundingBox { min, max })
+    }
+
+    /// All points inside the box, left to right and top to bottom.
+    pub fn points(&self) -> impl Iterator<Item = Point<T>> {
+        let (min, max) = (self.min, self.max);
+        let mut next = Some(min);
+
+        std::iter::from_fn(move || {
+            let point = next?;
+            next = if point.x < max.x {
+                Some(Point::new(point.x + T::ONE, point.y))
+            } else if point.y < max.y {
+                Some(Point::new(min.x, point.y + T::ONE))
+            } else {
+                None
+            };
+            Some(point)
+        })
+    }
+}
+
+impl<T: Signed> Hash for BoundingBox<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.min.hash(state);
+        self.max.hash(state);
+    }
+}
+
+/// Twice the area enclosed by a polygon, using the shoelace formula. The area of a polygon with
+/// lattice vertices is a multiple of ½, so twice the area is always an integer.
+///
+/// The polygon is closed from the last back to the first vertex, and must not intersect itself.
+pub fn double_area<T: Signed>(vertices: &[Point<T>]) -> T {
+    let mut sum = T::ZERO;
+    for (i, a) in vertices.iter().enumerate() {
+        let b = vertices[(i + 1) % vertices.len()];
+        sum += a.x * b.y - a.y * b.x;
+    }
+    sum.abs()
+}
+
+/// Number of lattice points on the edges of a polygon, see [`double_area`].
+pub fn boundary_points<T: Signed>(vertices: &[Point<T>]) -> T {
+    let mut sum = T::ZERO;
+    for (i, &a) in vertices.iter().enumerate() {
+        let b = vertices[(i + 1) % vertices.len()];
+        let step = (b - a).reduce();
+        // steps along an axis are reduced to length 1, diagonal steps to their gcd.
+        sum += if step.x != T::ZERO {
+            (b.x - a.x) / step.x
+        } else if step.y != T::ZERO {
+            (b.y - a.y) / step.y
+        } else {
+            T::ZERO
+        };
+    }
+    sum
+}
+
+/// Number of lattice points strictly inside a polygon, using Pick's theorem
+/// `A = I + B / 2 - 1`. See [`double_area`].
+pub fn interior_points<T: Signed>(vertices: &[Point<T>]) -> T {
+    let two = T::ONE + T::ONE;
+    (double_area(vertices) - boundary_points(vertices) + two) / two
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn draws_bresenham_lines() {
+        let line: Vec<_> = bresenham(Point::new(0, 0), Point::new(5, 2)).collect();
+        assert_eq!(
+            line,
+            vec![
+                Point::new(0, 0),
+                Point::new(1, 0),
+                Point::new(2, 1),
+                Point::new(3, 1),
+                Point::new(4, 2),
+                Point::new(5, 2),
+            ]
+        );
+        assert_eq!(bresenham(Point::new(3, 3), Point::new(3, 3)).count(), 1);
+        assert!(bresenham(Point::new(5, 1), Point::new(-3, 6))
+            .collect::<Vec<_>>()
+            .windows(2)
+            .all(|w| w[0].chebyshev_distance(&w[1]) == 1));
+    }
+
+    #[test]
+    fn steps_through_lattice_points() {
+        let line: Vec<Point<i64>> = lattice_line(Point::new(1, 1), Point::new(7, -8)).collect();
+        assert_eq!(
+            line,
+            vec![
+                Point::new(1, 1),
+                Point::new(3, -2),
+                Point::new(5, -5),
+                Point::new(7, -8),
+            ]
+        );
+        assert_eq!(lattice_line(Point::new(0, 0), Point::new(3, 5)).count(), 2);
+    }
+
+    #[test]
+    fn combines_bounding_boxes() {
+        let a = BoundingBox::new(Point::new(2, 3), Point::new(0, 1));
+        assert_eq!(a.min, Point::new(0, 1));
+        assert_eq!((a.width(), a.height()), (3, 3));
+        assert!(a.contains(Point::new(2, 1)));
+        assert!(!a.contains(Point::new(3, 1)));
+
+        let b = BoundingBox::from_points([Point::new(1, 2), Point::new(5, 0)]).unwrap();
+        assert_eq!(
+            a.union(&b),
+            BoundingBox::new(Point::new(0, 0), Point::new(5, 3))
+        );
+        assert_eq!(
+            a.intersection(&b),
+            Some(BoundingBox::new(Point::new(1, 1), Point::new(2, 2)))
+        );
+        assert_eq!(
+            a.intersection(&BoundingBox::new(Point::new(3, 0), Point::new(4, 4))),
+            None
+        );
+        assert_eq!(BoundingBox::<i32>::from_points([]), None);
+
+        let points: Vec<_> = BoundingBox::new(Point::new(-1, 0), Point::new(0, 1))
+            .points()
+            .collect();
+        assert_eq!(
+            points,
+            vec![
+                Point::new(-1, 0),
+                Point::new(0, 0),
+                Point::new(-1, 1),
+                Point::new(0, 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn counts_polygon_points() {
+        // a right triangle with legs of 4 and 3.
+        let triangle = [Point::new(0, 0), Point::new(4, 0), Point::new(0, 3)];
+        assert_eq!(double_area(&triangle), 12);
+        assert_eq!(boundary_points(&triangle), 8);
+        assert_eq!(interior_points(&triangle), 3);
+
+        // the orientation does not matter.
+        let mut reversed = triangle;
+        reversed.reverse();
+        assert_eq!(double_area(&reversed), 12);
+
+        let l_shape: [Point<i64>; 6] = [
+            Point::new(0, 0),
+            Point::new(2, 0),
+            Point::new(2, 1),
+            Point::new(1, 1),
+            Point::new(1, 2),
+            Point::new(0, 2),
+        ];
+        assert_eq!(double_area(&l_shape), 6);
+        assert_eq!(boundary_points(&l_shape), 8);
+        assert_eq!(interior_points(&l_shape), 0);
+    }
+}
@@ -21,18 +21,53 @@
 //! creates a grid of the same size, that can be used for in BFS algorithms for tracking visited
 //! location or for tracking cost in Djikstra.
 //!
+//! [`parse_with`] maps every byte to a typed cell instead, reporting the row and column of the
+//! first byte that could not be mapped:
+//!
+//! ```
+//!   # use advent_of_code::util::grid::Grid;
+//!
+//!   let grid = Grid::parse_with("12\n34", |b| (b as char).to_digit(10).ok_or("not a digit"));
+//!   assert_eq!(grid.unwrap().height(), 2);
+//! ```
+//!
+//! Words can be searched for along all 8 directions with [`find_word`], and 2 dimensional
+//! patterns with wildcards in any rotation or mirror image with [`find_pattern_variants`]:
+//!
+//! ```
+//!   # use advent_of_code::util::grid::Grid;
+//!   # use advent_of_code::util::point::Point;
+//!
+//!   let grid = Grid::parse("XMAS\nMM.S\nA.A.\nSM.S");
+//!   assert_eq!(grid.find_word(b"XMAS").count(), 3);
+//!
+//!   let pattern = Grid::parse_pattern("M.S\n.A.\nM.S", b'.');
+//!   let matches = grid.find_pattern_variants(&pattern);
+//!   assert_eq!(matches.len(), 1);
+//!   assert_eq!(matches[0].origin, Point::new(1, 1));
+//! ```
+//!
 //! [`Point`]: crate::util::point
 //! [`parse`]: Grid::parse
+//! [`parse_with`]: Grid::parse_with
+//! [`find_word`]: Grid::find_word
+//! [`find_pattern_variants`]: Grid::find_pattern_variants
 //! [`same_size_with`]: Grid::same_size_with
 //!
 //! Source: https://github.com/maneatingape/advent-of-code-rust/blob/main/src/util/grid.rs
 
 use core::fmt;
-use std::fmt::Debug;
+use std::collections::{HashMap, HashSet};
+use std::convert::Infallible;
+use std::error::Error;
+use std::fmt::{Debug, Display};
+use std::hash::Hash;
 use std::ops::{Index, IndexMut};
 
-use crate::util::point;
+use miette::Report;
+
 use crate::util::point::*;
+use crate::util::{parse, point};
 
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct Grid<T> {
@@ -41,20 +76,97 @@ pub struct Grid<T> {
     bytes: Vec<T>,
 }
 
+/// Reasons why input could not be parsed into a [`Grid`]. Rows and columns are zero based.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseGridError<E> {
+    /// The input has no lines, or its first line is empty.
+    Empty,
+    /// A line has a different length than the first line.
+    Ragged {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+    /// The mapping function rejected a byte.
+    Cell {
+        row: usize,
+        column: usize,
+        byte: u8,
+        error: E,
+    },
+}
+
+impl<E: Display> Display for ParseGridError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseGridError::Empty => write!(f, "grid is empty"),
+            ParseGridError::Ragged {
+                row,
+                expected,
+                found,
+            } => write!(
+                f,
+                "line {row} has length {found}, expected {expected} like the first line"
+            ),
+            ParseGridError::Cell {
+                row,
+                column,
+                byte,
+                error,
+            } => write!(
+                f,
+                "invalid cell {:?} at line {row}, column {column}: {error}",
+                *byte as char
+            ),
+        }
+    }
+}
+
+impl<E: Debug + Display> Error for ParseGridError<E> {}
+
+impl<E: Display> ParseGridError<E> {
+    /// Converts the error into a report that points at the offending line or cell of `input`,
+    /// which must be the input that was parsed.
+    pub fn report(&self, input: &str) -> Report {
+        match self {
+            ParseGridError::Empty => parse::error_at(input, 0, 0, self.to_string()),
+            ParseGridError::Ragged {
+                row,
+                expected,
+                found,
+            } => parse::error_at(
+                input,
+                parse::offset_of(input, *row, 0),
+                *found,
+                format!("line has length {found}, expected {expected} like the first line"),
+            ),
+            ParseGridError::Cell {
+                row,
+                column,
+                byte,
+                error,
+            } => parse::error_at(
+                input,
+                parse::offset_of(input, *row, *column),
+                1,
+                format!("invalid cell {:?}: {error}", *byte as char),
+            ),
+        }
+    }
+}
+
 impl Grid<u8> {
+    /// Parses a rectangle of ASCII characters. Panics if the input is empty or ragged, use
+    /// [`Grid::parse_with`] to handle these cases.
     #[inline]
     pub fn parse(input: &str) -> Self {
-        let raw: Vec<_> = input.lines().map(str::as_bytes).collect();
-        let width = raw[0].len() as i32;
-        let height = raw.len() as i32;
+        Self::parse_with(input, Ok::<u8, Infallible>).unwrap_or_else(|e| panic!("{e}"))
+    }
 
-        let mut bytes = Vec::with_capacity((width * height) as usize);
-        raw.iter().for_each(|slice| bytes.extend_from_slice(slice));
-        Grid {
-            width,
-            height,
-            bytes,
-        }
+    /// Parses a pattern for [`Grid::find_pattern`], where `wildcard` matches any value.
+    pub fn parse_pattern(input: &str, wildcard: u8) -> Grid<Option<u8>> {
+        Grid::parse_with(input, |b| Ok::<_, Infallible>((b != wildcard).then_some(b)))
+            .unwrap_or_else(|e| panic!("{e}"))
     }
 
     pub fn print(&self) {
@@ -69,14 +181,115 @@ impl Grid<u8> {
 }
 
 impl<T: Copy + PartialEq> Grid<T> {
+    /// Returns the first point whose cell equals `needle`, in reading order.
     #[inline]
     pub fn find(&self, needle: T) -> Option<Point> {
-        let to_point = |index| {
-            let x = (index as i32) % self.width;
-            let y = (index as i32) / self.width;
-            Point::new(x, y)
+        self.bytes
+            .iter()
+            .position(|&h| h == needle)
+            .map(|index| self.to_point(index))
+    }
+}
+
+impl<T: PartialEq> Grid<T> {
+    /// All points whose cell equals `needle`, in reading order.
+    #[inline]
+    pub fn find_all<'a>(&'a self, needle: &'a T) -> impl Iterator<Item = Point> + 'a {
+        self.bytes
+            .iter()
+            .enumerate()
+            .filter(move |(_, cell)| *cell == needle)
+            .map(|(index, _)| self.to_point(index))
+    }
+
+    /// Number of cells equal to `needle`.
+    #[inline]
+    pub fn count(&self, needle: &T) -> usize {
+        self.bytes.iter().filter(|&cell| cell == needle).count()
+    }
+}
+
+impl<T: Clone + Eq + Hash> Grid<T> {
+    /// Maps every distinct value to the points that hold it, each in reading order.
+    pub fn group_by_value(&self) -> HashMap<T, Vec<Point>> {
+        let mut groups: HashMap<T, Vec<Point>> = HashMap::new();
+        for (point, cell) in self.iter() {
+            groups.entry(cell.clone()).or_default().push(point);
+        }
+        groups
+    }
+}
+
+impl<T: PartialEq> Grid<T> {
+    /// Returns `true` if the cells starting at `start` and stepping by `direction` spell `word`.
+    pub fn matches_word(&self, start: Point, direction: Point, word: &[T]) -> bool {
+        word.iter()
+            .enumerate()
+            .all(|(i, letter)| self.get(start + direction * i as i32) == Some(letter))
+    }
+
+    /// All occurrences of `word` along any of the 8 directions in [`DIAGONAL`], as pairs of the
+    /// first point and the direction, in reading order.
+    pub fn find_word<'a>(&'a self, word: &'a [T]) -> impl Iterator<Item = (Point, Point)> + 'a {
+        let starts = match word.first() {
+            Some(first) => self.find_all(first).collect(),
+            None => Vec::new(),
         };
-        self.bytes.iter().position(|&h| h == needle).map(to_point)
+        starts.into_iter().flat_map(move |start| {
+            DIAGONAL
+                .into_iter()
+                .filter(move |&direction| self.matches_word(start, direction, word))
+                .map(move |direction| (start, direction))
+        })
+    }
+
+    /// Returns `true` if `pattern` matches with its top left corner at `origin`. `None` cells
+    /// of the pattern match any value.
+    pub fn matches_pattern(&self, origin: Point, pattern: &Grid<Option<T>>) -> bool {
+        pattern.iter().all(|(offset, expected)| match expected {
+            None => self.contains(origin + offset),
+            Some(expected) => self.get(origin + offset) == Some(expected),
+        })
+    }
+
+    /// Top left corners of all positions where `pattern` matches, in reading order.
+    pub fn find_pattern<'a>(
+        &'a self,
+        pattern: &'a Grid<Option<T>>,
+    ) -> impl Iterator<Item = Point> + 'a {
+        let (w, h) = (
+            self.width - pattern.width + 1,
+            self.height - pattern.height + 1,
+        );
+        (0..h)
+            .flat_map(move |y| (0..w).map(move |x| Point::new(x, y)))
+            .filter(move |&origin| self.matches_pattern(origin, pattern))
+    }
+}
+
+impl<T: Clone + PartialEq> Grid<T> {
+    /// Matches every distinct rotation and mirror image of `pattern`, see [`Orientation`].
+    /// Variants that look the same, e.g. because the pattern is symmetric, are only matched once.
+    pub fn find_pattern_variants(&self, pattern: &Grid<Option<T>>) -> Vec<PatternMatch> {
+        let mut variants: Vec<(Orientation, Grid<Option<T>>)> = Vec::new();
+        for orientation in Orientation::ALL {
+            let variant = orientation.apply(pattern);
+            if variants.iter().all(|(_, seen)| *seen != variant) {
+                variants.push((orientation, variant));
+            }
+        }
+
+        let mut matches: Vec<PatternMatch> = variants
+            .iter()
+            .flat_map(|(orientation, variant)| {
+                self.find_pattern(variant).map(|origin| PatternMatch {
+                    origin,
+                    orientation: *orientation,
+                })
+            })
+            .collect();
+        matches.sort_by_key(|m| (m.origin.y, m.origin.x));
+        matches
     }
 }
 
@@ -90,7 +303,149 @@ impl<T: Copy> Grid<T> {
     }
 }
 
+impl<T: Clone> Grid<T> {
+    /// Swaps rows and columns, so that the cell at `(x, y)` moves to `(y, x)`.
+    pub fn transpose(&self) -> Self {
+        Grid::from_fn(self.height, self.width, |p| {
+            self[Point::new(p.y, p.x)].clone()
+        })
+    }
+
+    /// Rotates the grid clockwise by `quarter_turns` times 90°. Negative values rotate
+    /// counter-clockwise.
+    pub fn rotate(&self, quarter_turns: i32) -> Self {
+        let (w, h) = (self.width, self.height);
+        match quarter_turns.rem_euclid(4) {
+            0 => self.clone(),
+            1 => Grid::from_fn(h, w, |p| self[Point::new(p.y, h - 1 - p.x)].clone()),
+            2 => Grid::from_fn(w, h, |p| self[Point::new(w - 1 - p.x, h - 1 - p.y)].clone()),
+            _ => Grid::from_fn(h, w, |p| self[Point::new(w - 1 - p.y, p.x)].clone()),
+        }
+    }
+
+    /// Mirrors the grid left to right.
+    pub fn flip_horizontal(&self) -> Self {
+        let w = self.width;
+        Grid::from_fn(w, self.height, |p| {
+            self[Point::new(w - 1 - p.x, p.y)].clone()
+        })
+    }
+
+    /// Mirrors the grid top to bottom.
+    pub fn flip_vertical(&self) -> Self {
+        let h = self.height;
+        Grid::from_fn(self.width, h, |p| {
+            self[Point::new(p.x, h - 1 - p.y)].clone()
+        })
+    }
+
+    /// Returns the rectangle of the given size whose top left corner is `origin`.
+    /// Panics if the rectangle does not fit into the grid.
+    pub fn crop(&self, origin: Point, width: i32, height: i32) -> Self {
+        assert!(
+            width >= 0
+                && height >= 0
+                && origin.x >= 0
+                && origin.y >= 0
+                && origin.x + width <= self.width
+                && origin.y + height <= self.height,
+            "crop of {width}x{height} at {origin:?} exceeds grid of {}x{}",
+            self.width,
+            self.height
+        );
+        Grid::from_fn(width, height, |p| self[origin + p].clone())
+    }
+
+    /// Surrounds the grid with a border of `size` cells of `value` on every side.
+    pub fn pad(&self, size: i32, value: T) -> Self {
+        assert!(size >= 0, "padding of {size} must not be negative");
+        let offset = Point::new(size, size);
+        Grid::from_fn(self.width + 2 * size, self.height + 2 * size, |p| {
+            self.get(p - offset).unwrap_or(&value).clone()
+        })
+    }
+
+    /// Repeats the grid `x_times` to the right and `y_times` downwards.
+    pub fn tile(&self, x_times: i32, y_times: i32) -> Self {
+        assert!(
+            x_times >= 0 && y_times >= 0,
+            "tiling {x_times}x{y_times} times must not be negative"
+        );
+        Grid::from_fn(self.width * x_times, self.height * y_times, |p| {
+            self[Point::new(p.x % self.width, p.y % self.height)].clone()
+        })
+    }
+}
+
 impl<T> Grid<T> {
+    /// Creates a grid by calling `f` for every point, left to right and top to bottom.
+    pub fn from_fn(width: i32, height: i32, mut f: impl FnMut(Point) -> T) -> Self {
+        assert!(
+            width >= 0 && height >= 0,
+            "grid of {width}x{height} must not have a negative size"
+        );
+        let mut bytes = Vec::with_capacity((width * height) as usize);
+        for y in 0..height {
+            for x in 0..width {
+                bytes.push(f(Point::new(x, y)));
+            }
+        }
+        Grid {
+            width,
+            height,
+            bytes,
+        }
+    }
+
+    /// Parses a rectangle of ASCII characters, mapping every byte to a cell with `f`.
+    pub fn parse_with<E>(
+        input: &str,
+        mut f: impl FnMut(u8) -> Result<T, E>,
+    ) -> Result<Self, ParseGridError<E>> {
+        let raw: Vec<_> = input.lines().map(str::as_bytes).collect();
+        let width = raw.first().map_or(0, |line| line.len());
+        if width == 0 {
+            return Err(ParseGridError::Empty);
+        }
+
+        let mut bytes = Vec::with_capacity(width * raw.len());
+        for (row, line) in raw.iter().enumerate() {
+            if line.len() != width {
+                return Err(ParseGridError::Ragged {
+                    row,
+                    expected: width,
+                    found: line.len(),
+                });
+            }
+
+            for (column, &byte) in line.iter().enumerate() {
+                let cell = f(byte).map_err(|error| ParseGridError::Cell {
+                    row,
+                    column,
+                    byte,
+                    error,
+                })?;
+                bytes.push(cell);
+            }
+        }
+
+        Ok(Grid {
+            width: width as i32,
+            height: raw.len() as i32,
+            bytes,
+        })
+    }
+
+    #[inline]
+    pub fn width(&self) -> i32 {
+        self.width
+    }
+
+    #[inline]
+    pub fn height(&self) -> i32 {
+        self.height
+    }
+
     #[inline]
     pub fn same_size_with<U: Copy>(&self, value: U) -> Grid<U> {
         Grid {
@@ -104,6 +459,326 @@ impl<T> Grid<T> {
     pub fn contains(&self, point: Point) -> bool {
         point.x >= 0 && point.x < self.width && point.y >= 0 && point.y < self.height
     }
+
+    /// Converts an index into the backing `vec` to the point it stores, the inverse of
+    /// [`to_index`](Self::to_index). The index has to be in bounds, so grids without cells have
+    /// no valid indices.
+    #[inline]
+    pub fn to_point(&self, index: usize) -> Point {
+        assert!(
+            index < self.bytes.len(),
+            "index {index} is out of bounds of a grid of {}x{}",
+            self.width,
+            self.height
+        );
+        let index = index as i32;
+        Point::new(index % self.width, index / self.width)
+    }
+
+    /// Converts a point to its index into the backing `vec`, or `None` if the point is out of
+    /// bounds.
+    #[inline]
+    pub fn to_index(&self, point: Point) -> Option<usize> {
+        self.contains(point)
+            .then(|| (self.width * point.y + point.x) as usize)
+    }
+
+    /// Returns the cell at `point`, or `None` if the point is out of bounds.
+    #[inline]
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.contains(point).then(|| &self[point])
+    }
+
+    #[inline]
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.contains(point).then(|| &mut self[point])
+    }
+
+    /// In-bounds neighbors of `point` in the order of [`ORTHOGONAL`].
+    #[inline]
+    pub fn orthogonal_neighbors(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        self.neighbors_in(point, &ORTHOGONAL)
+    }
+
+    /// In-bounds neighbors of `point` including diagonals, in the order of [`DIAGONAL`].
+    #[inline]
+    pub fn diagonal_neighbors(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        self.neighbors_in(point, &DIAGONAL)
+    }
+
+    fn neighbors_in<'a>(
+        &'a self,
+        point: Point,
+        directions: &'a [Point],
+    ) -> impl Iterator<Item = Point> + 'a {
+        directions
+            .iter()
+            .map(move |&direction| point + direction)
+            .filter(|&neighbor| self.contains(neighbor))
+    }
+
+    /// Maps any point, including negative ones, into bounds by wrapping around the edges as if
+    /// the grid was a torus.
+    #[inline]
+    pub fn wrap(&self, point: Point) -> Point {
+        point % Point::new(self.width, self.height)
+    }
+
+    /// Returns the cell at `point` after wrapping it into bounds, see [`Grid::wrap`].
+    #[inline]
+    pub fn get_wrapping(&self, point: Point) -> &T {
+        &self[self.wrap(point)]
+    }
+
+    #[inline]
+    pub fn get_wrapping_mut(&mut self, point: Point) -> &mut T {
+        let point = self.wrap(point);
+        &mut self[point]
+    }
+
+    /// Neighbors of `point` in the order of [`ORTHOGONAL`], wrapping around the edges. Grids
+    /// narrower than 3 cells yield the same neighbor more than once.
+    #[inline]
+    pub fn orthogonal_neighbors_wrapping(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        ORTHOGONAL
+            .iter()
+            .map(move |&direction| self.wrap(point + direction))
+    }
+
+    /// Neighbors of `point` including diagonals in the order of [`DIAGONAL`], wrapping around
+    /// the edges.
+    #[inline]
+    pub fn diagonal_neighbors_wrapping(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        DIAGONAL
+            .iter()
+            .map(move |&direction| self.wrap(point + direction))
+    }
+
+    /// All points of the grid, left to right and top to bottom.
+    #[inline]
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    /// All cells together with their point, left to right and top to bottom.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.bytes.iter())
+    }
+
+    /// Rows of the grid from top to bottom.
+    #[inline]
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // grids without columns have no cells, which would make a chunk size of 0.
+        self.bytes.chunks_exact(self.width.max(1) as usize)
+    }
+
+    /// Columns of the grid from left to right, each iterating its cells from top to bottom.
+    #[inline]
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width as usize)
+            .map(move |x| self.bytes.iter().skip(x).step_by(self.width as usize))
+    }
+}
+
+impl<T: PartialEq> Grid<T> {
+    /// Returns the region of orthogonally connected cells equal to the cell at `start`.
+    pub fn flood_fill(&self, start: Point) -> Region {
+        self.flood_fill_by(start, |a, b| a == b)
+    }
+
+    /// Labels every maximal region of orthogonally connected, equal cells.
+    pub fn components(&self) -> Components {
+        self.components_by(|a, b| a == b)
+    }
+}
+
+impl<T> Grid<T> {
+    /// Returns the region reachable from `start`, stepping orthogonally from a cell to a
+    /// neighbor whenever `connected(cell, neighbor)` holds.
+    pub fn flood_fill_by(&self, start: Point, connected: impl Fn(&T, &T) -> bool) -> Region {
+        let mut visited = self.same_size_with(false);
+        self.fill(start, &connected, &mut visited)
+    }
+
+    /// Labels every region of cells connected by `connected`, see [`Grid::flood_fill_by`].
+    /// Regions are numbered in reading order of their first cell.
+    pub fn components_by(&self, connected: impl Fn(&T, &T) -> bool) -> Components {
+        let mut visited = self.same_size_with(false);
+        let mut labels = self.same_size_with(0);
+        let mut regions = Vec::new();
+
+        for point in self.points() {
+            if visited[point] {
+                continue;
+            }
+
+            let region = self.fill(point, &connected, &mut visited);
+            for &member in &region.points {
+                labels[member] = regions.len();
+            }
+            regions.push(region);
+        }
+
+        Components { labels, regions }
+    }
+
+    fn fill(
+        &self,
+        start: Point,
+        connected: &impl Fn(&T, &T) -> bool,
+        visited: &mut Grid<bool>,
+    ) -> Region {
+        let mut points = vec![start];
+        let mut todo = vec![start];
+        visited[start] = true;
+
+        while let Some(point) = todo.pop() {
+            for next in self.orthogonal_neighbors(point) {
+                if !visited[next] && connected(&self[point], &self[next]) {
+                    visited[next] = true;
+                    points.push(next);
+                    todo.push(next);
+                }
+            }
+        }
+
+        Region::new(points)
+    }
+}
+
+/// One of the 8 ways to rotate and mirror a pattern: the pattern is first mirrored left to right
+/// if `mirrored` is set, then rotated clockwise by `quarter_turns` times 90°.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Orientation {
+    pub quarter_turns: i32,
+    pub mirrored: bool,
+}
+
+impl Orientation {
+    pub const ALL: [Orientation; 8] = [
+        Orientation::new(0, false),
+        Orientation::new(1, false),
+        Orientation::new(2, false),
+        Orientation::new(3, false),
+        Orientation::new(0, true),
+        Orientation::new(1, true),
+        Orientation::new(2, true),
+        Orientation::new(3, true),
+    ];
+
+    pub const fn new(quarter_turns: i32, mirrored: bool) -> Self {
+        Orientation {
+            quarter_turns,
+            mirrored,
+        }
+    }
+
+    /// Returns `grid` in this orientation.
+    pub fn apply<T: Clone>(self, grid: &Grid<T>) -> Grid<T> {
+        if self.mirrored {
+            grid.flip_horizontal().rotate(self.quarter_turns)
+        } else {
+            grid.rotate(self.quarter_turns)
+        }
+    }
+}
+
+/// A match of [`Grid::find_pattern_variants`]: the top left corner of the matched area, and
+/// the orientation the pattern was matched in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PatternMatch {
+    pub origin: Point,
+    pub orientation: Orientation,
+}
+
+/// The connected regions of a grid, see [`Grid::components`].
+pub struct Components {
+    /// Index into `regions` for every cell of the grid.
+    pub labels: Grid<usize>,
+    pub regions: Vec<Region>,
+}
+
+/// A set of points, usually a connected region of a grid, with helpers for its geometry.
+#[derive(Clone, Debug)]
+pub struct Region {
+    points: Vec<Point>,
+    members: HashSet<Point>,
+}
+
+impl Region {
+    pub fn new(points: Vec<Point>) -> Self {
+        let members = points.iter().copied().collect();
+        Region { points, members }
+    }
+
+    /// Points of the region in the order they were found.
+    #[inline]
+    pub fn points(&self) -> &[Point] {
+        &self.points
+    }
+
+    #[inline]
+    pub fn contains(&self, point: Point) -> bool {
+        self.members.contains(&point)
+    }
+
+    /// Number of cells.
+    #[inline]
+    pub fn area(&self) -> usize {
+        self.points.len()
+    }
+
+    /// Number of cell edges that border a cell outside of the region.
+    pub fn perimeter(&self) -> usize {
+        self.points
+            .iter()
+            .flat_map(|&p| ORTHOGONAL.map(|d| p + d))
+            .filter(|&p| !self.contains(p))
+            .count()
+    }
+
+    /// Number of corners of the outline, including the outlines of holes. As every side of the
+    /// outline ends in a corner, this is also the number of straight sides.
+    pub fn corners(&self) -> usize {
+        let mut corners = 0;
+
+        for &point in &self.points {
+            // every corner of a cell is checked with the two orthogonal neighbors that touch it.
+            for (a, b) in [(UP, RIGHT), (RIGHT, DOWN), (DOWN, LEFT), (LEFT, UP)] {
+                let has_a = self.contains(point + a);
+                let has_b = self.contains(point + b);
+                let has_diagonal = self.contains(point + a + b);
+
+                let is_convex = !has_a && !has_b;
+                let is_concave = has_a && has_b && !has_diagonal;
+                if is_convex || is_concave {
+                    corners += 1;
+                }
+            }
+        }
+
+        corners
+    }
+
+    /// Number of straight sides of the outline, see [`Region::corners`].
+    #[inline]
+    pub fn sides(&self) -> usize {
+        self.corners()
+    }
+
+    /// Returns the top left and bottom right corner of the smallest rectangle containing the
+    /// region, or `None` if it is empty.
+    pub fn bounding_box(&self) -> Option<(Point, Point)> {
+        let first = *self.points.first()?;
+        Some(self.points.iter().fold((first, first), |(min, max), p| {
+            (
+                Point::new(min.x.min(p.x), min.y.min(p.y)),
+                Point::new(max.x.max(p.x), max.y.max(p.y)),
+            )
+        }))
+    }
 }
 
 impl<T> Index<Point> for Grid<T> {
@@ -135,3 +810,339 @@ impl Debug for Grid<u8> {
         Ok(())
     }
 }
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Grid, Orientation, ParseGridError};
+    use crate::util::point::{Point, DOWN, LEFT, ORIGIN, RIGHT, UP};
+
+    fn digit(b: u8) -> Result<u32, &'static str> {
+        (b as char).to_digit(10).ok_or("not a digit")
+    }
+
+    #[test]
+    fn finds_words_in_all_directions() {
+        let grid = Grid::parse("SAMX\n.AA.\nXMAS");
+        let mut found: Vec<_> = grid.find_word(b"XMAS").collect();
+        found.sort_by_key(|&(start, direction)| (start.y, start.x, direction.y, direction.x));
+        assert_eq!(
+            found,
+            vec![(Point::new(3, 0), LEFT), (Point::new(0, 2), RIGHT),]
+        );
+        assert!(grid.matches_word(Point::new(0, 2), RIGHT, b"XMAS"));
+        assert!(!grid.matches_word(Point::new(1, 2), RIGHT, b"XMAS"));
+        assert_eq!(grid.find_word(b"").count(), 0);
+
+        let grid = Grid::parse("X...\n.M..\n..A.\n...S");
+        let found: Vec<_> = grid.find_word(b"XMAS").collect();
+        assert_eq!(found, vec![(Point::new(0, 0), Point::new(1, 1))]);
+    }
+
+    #[test]
+    fn finds_patterns_with_wildcards() {
+        let grid = Grid::parse("ab.\nxbb\nabz");
+        let pattern = Grid::parse_pattern("?b\n?b", b'?');
+        assert_eq!(
+            grid.find_pattern(&pattern).collect::<Vec<_>>(),
+            vec![Point::new(0, 0), Point::new(0, 1)]
+        );
+        assert_eq!(
+            grid.find_pattern(&Grid::parse_pattern("????", b'?'))
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn finds_rotated_and_mirrored_patterns() {
+        let pattern = Grid::parse_pattern("M.S\n.A.\nM.S", b'.');
+        let grid = Grid::parse("M.M.S.S\n.A...A.\nS.S.M.M");
+        let matches = grid.find_pattern_variants(&pattern);
+        assert_eq!(matches.len(), 2);
+        assert_eq!(matches[0].origin, Point::new(0, 0));
+        assert_eq!(matches[0].orientation, Orientation::new(1, false));
+        assert_eq!(matches[1].origin, Point::new(4, 0));
+        assert_eq!(matches[1].orientation, Orientation::new(3, false));
+
+        // an asymmetric pattern has 8 distinct variants.
+        let pattern = Grid::parse_pattern("ab\nc.", b'.');
+        let grid = Grid::parse("ab\nc.").pad(1, b'.');
+        let matches = grid.find_pattern_variants(&pattern);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].orientation, Orientation::new(0, false));
+        assert_eq!(
+            grid.flip_horizontal().find_pattern_variants(&pattern)[0].orientation,
+            Orientation::new(0, true)
+        );
+    }
+
+    #[test]
+    fn finds_and_counts_values() {
+        let grid = Grid::parse("a.b\n.ab");
+        assert_eq!(grid.find(b'b'), Some(Point::new(2, 0)));
+        assert_eq!(
+            grid.find_all(&b'a').collect::<Vec<_>>(),
+            vec![Point::new(0, 0), Point::new(1, 1)]
+        );
+        assert_eq!(grid.count(&b'.'), 2);
+        assert_eq!(grid.count(&b'z'), 0);
+
+        let groups = grid.group_by_value();
+        assert_eq!(groups.len(), 3);
+        assert_eq!(groups[&b'b'], vec![Point::new(2, 0), Point::new(2, 1)]);
+    }
+
+    #[test]
+    fn handles_grids_without_cells() {
+        for (width, height) in [(0, 3), (3, 0)] {
+            let grid = Grid::from_fn(width, height, |_| b'.');
+            assert_eq!(grid.rows().count(), 0);
+            assert_eq!(grid.find_all(&b'.').count(), 0);
+            assert_eq!(grid.renderer(|&b| b as char).to_string(), "");
+            assert_eq!(grid.tile(2, 2).rows().count(), 0);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn rejects_indices_out_of_bounds() {
+        Grid::from_fn(0, 3, |_| b'.').to_point(0);
+    }
+
+    #[test]
+    fn converts_indices_and_points() {
+        let grid = Grid::new(3, 2, 0);
+        assert_eq!(grid.to_point(4), Point::new(1, 1));
+        assert_eq!(grid.to_index(Point::new(1, 1)), Some(4));
+        assert_eq!(grid.to_index(Point::new(3, 0)), None);
+        for (index, point) in grid.points().enumerate() {
+            assert_eq!(grid.to_point(index), point);
+        }
+    }
+
+    #[test]
+    fn parses_typed_cells() {
+        let grid = Grid::parse_with("12\n34\n", digit).unwrap();
+        assert_eq!(grid.width(), 2);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid[Point::new(1, 0)], 2);
+        assert_eq!(grid[Point::new(0, 1)], 3);
+    }
+
+    #[test]
+    fn reports_position_of_invalid_cells() {
+        assert_eq!(
+            Grid::parse_with("12\n3x", digit).err(),
+            Some(ParseGridError::Cell {
+                row: 1,
+                column: 1,
+                byte: b'x',
+                error: "not a digit"
+            })
+        );
+        assert_eq!(
+            Grid::parse_with("12\n3x", digit)
+                .err()
+                .map(|e| e.to_string())
+                .as_deref(),
+            Some("invalid cell 'x' at line 1, column 1: not a digit")
+        );
+
+        let input = "12\n3x\n";
+        let report = Grid::parse_with(input, digit).err().unwrap().report(input);
+        let label = report.labels().unwrap().next().unwrap();
+        assert_eq!((label.offset(), label.len()), (4, 1));
+        assert_eq!(report.to_string(), "invalid cell 'x': not a digit");
+
+        let input = "12\n3\n";
+        let report = Grid::parse_with(input, digit).err().unwrap().report(input);
+        let label = report.labels().unwrap().next().unwrap();
+        assert_eq!((label.offset(), label.len()), (3, 1));
+    }
+
+    #[test]
+    fn rejects_empty_and_ragged_input() {
+        assert_eq!(
+            Grid::parse_with("", digit).err(),
+            Some(ParseGridError::Empty)
+        );
+        assert_eq!(
+            Grid::parse_with("\n12", digit).err(),
+            Some(ParseGridError::Empty)
+        );
+        assert_eq!(
+            Grid::parse_with("12\n3", digit).err(),
+            Some(ParseGridError::Ragged {
+                row: 1,
+                expected: 2,
+                found: 1
+            })
+        );
+    }
+
+    #[test]
+    fn checks_bounds() {
+        let grid = Grid::parse("12\n34");
+        assert_eq!(grid.get(Point::new(1, 1)), Some(&b'4'));
+        assert_eq!(grid.get(Point::new(2, 0)), None);
+        assert_eq!(grid.get(Point::new(0, -1)), None);
+    }
+
+    #[test]
+    fn iterates_neighbors() {
+        let grid = Grid::parse("123\n456\n789");
+        let center = Point::new(1, 1);
+
+        let orthogonal: Vec<_> = grid.orthogonal_neighbors(center).collect();
+        assert_eq!(
+            orthogonal,
+            vec![center + UP, center + DOWN, center + LEFT, center + RIGHT]
+        );
+        assert_eq!(grid.diagonal_neighbors(center).count(), 8);
+
+        let corner: Vec<_> = grid.orthogonal_neighbors(ORIGIN).collect();
+        assert_eq!(corner, vec![DOWN, RIGHT]);
+        assert_eq!(grid.diagonal_neighbors(ORIGIN).count(), 3);
+    }
+
+    #[test]
+    fn iterates_points_rows_and_columns() {
+        let grid = Grid::parse("12\n34\n56");
+
+        let cells: Vec<_> = grid.iter().map(|(point, &b)| (point, b)).collect();
+        assert_eq!(cells[3], (Point::new(1, 1), b'4'));
+        assert_eq!(grid.points().count(), 6);
+
+        let rows: Vec<_> = grid.rows().collect();
+        assert_eq!(rows, vec![b"12", b"34", b"56"]);
+
+        let columns: Vec<Vec<u8>> = grid.columns().map(|c| c.copied().collect()).collect();
+        assert_eq!(columns, vec![b"135".to_vec(), b"246".to_vec()]);
+    }
+
+    #[test]
+    fn rotates_and_transposes() {
+        let grid = Grid::parse("123\n456");
+
+        assert_eq!(grid.transpose(), Grid::parse("14\n25\n36"));
+        assert_eq!(grid.rotate(1), Grid::parse("41\n52\n63"));
+        assert_eq!(grid.rotate(2), Grid::parse("654\n321"));
+        assert_eq!(grid.rotate(-1), Grid::parse("36\n25\n14"));
+        assert_eq!(grid.rotate(4), grid);
+    }
+
+    #[test]
+    fn flips() {
+        let grid = Grid::parse("123\n456");
+        assert_eq!(grid.flip_horizontal(), Grid::parse("321\n654"));
+        assert_eq!(grid.flip_vertical(), Grid::parse("456\n123"));
+    }
+
+    #[test]
+    fn crops_pads_and_tiles() {
+        let grid = Grid::parse("123\n456\n789");
+        assert_eq!(grid.crop(Point::new(1, 1), 2, 2), Grid::parse("56\n89"));
+        assert_eq!(Grid::parse("1").pad(1, b'.'), Grid::parse("...\n.1.\n..."));
+        assert_eq!(Grid::parse("12").tile(2, 2), Grid::parse("1212\n1212"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn rejects_crop_out_of_bounds() {
+        Grid::parse("12\n34").crop(Point::new(1, 1), 2, 1);
+    }
+
+    #[test]
+    fn crops_empty_areas() {
+        let grid = Grid::parse("12\n34");
+        assert_eq!(grid.crop(Point::new(1, 0), 0, 2).rows().count(), 0);
+        assert_eq!(grid.crop(Point::new(2, 2), 0, 0).height(), 0);
+        assert_eq!(grid.crop(Point::new(0, 1), 2, 0).width(), 2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn rejects_empty_crop_outside() {
+        Grid::parse("12\n34").crop(Point::new(3, 0), 0, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "must not be negative")]
+    fn rejects_negative_padding() {
+        Grid::parse("12\n34").pad(-1, b'.');
+    }
+
+    #[test]
+    #[should_panic(expected = "must not be negative")]
+    fn rejects_negative_tiling() {
+        Grid::parse("12\n34").tile(2, -1);
+    }
+
+    #[test]
+    #[should_panic(expected = "negative size")]
+    fn rejects_negative_sizes() {
+        Grid::from_fn(-1, 2, |_| 0);
+    }
+
+    #[test]
+    fn labels_components() {
+        let grid = Grid::parse("AAB\nABB\nCCB");
+        let components = grid.components();
+
+        assert_eq!(components.regions.len(), 3);
+        assert_eq!(components.labels[Point::new(0, 1)], 0);
+        assert_eq!(components.labels[Point::new(2, 2)], 1);
+        assert_eq!(components.labels[Point::new(1, 2)], 2);
+
+        let areas: Vec<_> = components.regions.iter().map(|r| r.area()).collect();
+        assert_eq!(areas, vec![3, 4, 2]);
+    }
+
+    #[test]
+    fn fills_by_predicate() {
+        let grid = Grid::parse("..#\n.##\n#..");
+        let open = |_: &u8, next: &u8| *next == b'.';
+
+        assert_eq!(grid.flood_fill_by(ORIGIN, open).area(), 3);
+        assert_eq!(grid.flood_fill_by(Point::new(2, 2), open).area(), 2);
+        assert_eq!(grid.flood_fill(Point::new(2, 0)).area(), 3);
+    }
+
+    #[test]
+    fn measures_region_geometry() {
+        // an `E` shaped region with a hole free outline of 12 sides.
+        let grid = Grid::parse("EEE\nEXX\nEEE\nEXX\nEEE");
+        let region = grid.flood_fill(ORIGIN);
+        assert_eq!(region.area(), 11);
+        assert_eq!(region.perimeter(), 24);
+        assert_eq!(region.sides(), 12);
+        assert_eq!(region.bounding_box(), Some((ORIGIN, Point::new(2, 4))));
+
+        // a ring has an outer and an inner outline.
+        let ring = Grid::parse("OOO\nOXO\nOOO").flood_fill(ORIGIN);
+        assert_eq!(ring.perimeter(), 16);
+        assert_eq!(ring.corners(), 8);
+    }
+
+    #[test]
+    fn wraps_around_edges() {
+        let mut grid = Grid::parse("123\n456");
+
+        assert_eq!(grid.wrap(Point::new(-1, -1)), Point::new(2, 1));
+        assert_eq!(grid.wrap(Point::new(7, 4)), Point::new(1, 0));
+        assert_eq!(*grid.get_wrapping(Point::new(-4, 3)), b'6');
+
+        *grid.get_wrapping_mut(Point::new(3, 2)) = b'0';
+        assert_eq!(grid[ORIGIN], b'0');
+
+        let neighbors: Vec<_> = grid.orthogonal_neighbors_wrapping(ORIGIN).collect();
+        assert_eq!(
+            neighbors,
+            vec![Point::new(0, 1), Point::new(0, 1), Point::new(2, 0), RIGHT]
+        );
+        assert!(grid
+            .diagonal_neighbors_wrapping(ORIGIN)
+            .all(|p| grid.contains(p)));
+        assert_eq!(grid.orthogonal_neighbors(ORIGIN).count(), 2);
+    }
+}
@@ -0,0 +1,364 @@
+//! Exports grids as images, for debugging simulations that are hard to follow as text.
+//!
+//! An [`Image`] is created from a [`Grid`] by mapping every cell to a color. It can be scaled up
+//! and saved as PPM or PNG. A [`FrameRecorder`] collects successive states of a simulation and
+//! writes them as numbered frames or as an animated GIF.
+//!
+//! ```no_run
+//!   # use advent_of_code::util::grid::Grid;
+//!   # use advent_of_code::util::image::{FrameRecorder, Image};
+//!   # use advent_of_code::util::point::Point;
+//!
+//!   let mut grid = Grid::parse("#..\n...");
+//!   let to_color = |&b: &u8| if b == b'#' { [255, 255, 255] } else { [0, 0, 0] };
+//!
+//!   Image::from_grid(&grid, to_color).scale(8).save("grid.png").unwrap();
+//!
+//!   // all frames of an animation need the same size.
+//!   let mut recorder = FrameRecorder::new().scale(8).delay(100);
+//!   for x in 0..3 {
+//!       grid[Point::new(x, 1)] = b'#';
+//!       recorder.record(Image::from_grid(&grid, to_color));
+//!       grid[Point::new(x, 1)] = b'.';
+//!   }
+//!   recorder.save_gif("grid.gif").unwrap();
+//! ```
+//!
+//! [`Grid`]: crate::util::grid::Grid
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+use crate::util::grid::Grid;
+
+/// Red, green and blue channels of a color.
+pub type Rgb = [u8; 3];
+
+/// RGB image with 8 bits per channel.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Image {
+    /// Creates an image with one pixel per cell of `grid`, colored by `to_color`.
+    pub fn from_grid<T>(grid: &Grid<T>, to_color: impl Fn(&T) -> Rgb) -> Self {
+        Image {
+            width: grid.width() as usize,
+            height: grid.height() as usize,
+            pixels: grid.rows().flatten().map(to_color).collect(),
+        }
+    }
+
+    #[inline]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    #[inline]
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the color of the pixel at `x`, `y`.
+    #[inline]
+    pub fn pixel(&self, x: usize, y: usize) -> Rgb {
+        self.pixels[y * self.width + x]
+    }
+
+    /// Sets the color of the pixel at `x`, `y`, e.g. to draw an overlay on top of the cells.
+    #[inline]
+    pub fn set_pixel(&mut self, x: usize, y: usize, color: Rgb) {
+        self.pixels[y * self.width + x] = color;
+    }
+
+    /// Scales the image up, drawing every pixel as a `factor` by `factor` square.
+    pub fn scale(&self, factor: usize) -> Self {
+        assert!(factor > 0, "scale factor must be positive");
+
+        let mut pixels = Vec::with_capacity(self.pixels.len() * factor * factor);
+        for row in self.pixels.chunks(self.width.max(1)) {
+            for _ in 0..factor {
+                for &pixel in row {
+                    pixels.extend(std::iter::repeat_n(pixel, factor));
+                }
+            }
+        }
+
+        Image {
+            width: self.width * factor,
+            height: self.height * factor,
+            pixels,
+        }
+    }
+
+    /// Writes the image as binary PPM (`P6`).
+    pub fn write_ppm(&self, w: &mut impl Write) -> io::Result<()> {
+        write!(w, "P6\n{} {}\n255\n", self.width, self.height)?;
+        w.write_all(self.pixels.as_flattened())
+    }
+
+    /// Writes the image as PNG.
+    pub fn write_png(&self, w: impl Write) -> io::Result<()> {
+        let mut encoder = png::Encoder::new(w, self.width as u32, self.height as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        let mut writer = encoder.write_header().map_err(io::Error::other)?;
+        writer
+            .write_image_data(self.pixels.as_flattened())
+            .map_err(io::Error::other)?;
+        writer.finish().map_err(io::Error::other)
+    }
+
+    /// Saves the image, picking the format from the extension of `path` (`ppm` or `png`).
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        let extension = path.extension().and_then(|extension| extension.to_str());
+        if !matches!(extension, Some("ppm" | "png")) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("unsupported image format: {}", path.display()),
+            ));
+        }
+
+        let mut file = BufWriter::new(File::create(path)?);
+        match extension {
+            Some("ppm") => self.write_ppm(&mut file)?,
+            _ => self.write_png(&mut file)?,
+        }
+        file.flush()
+    }
+}
+
+/// Collects successive images of a simulation and writes them as frames of an animation.
+#[derive(Clone, Debug)]
+pub struct FrameRecorder {
+    frames: Vec<Image>,
+    scale: usize,
+    delay_ms: u32,
+}
+
+impl Default for FrameRecorder {
+    fn default() -> Self {
+        FrameRecorder::new()
+    }
+}
+
+impl FrameRecorder {
+    /// Creates a recorder that writes unscaled frames with a delay of 100ms.
+    pub fn new() -> Self {
+        FrameRecorder {
+            frames: Vec::new(),
+            scale: 1,
+            delay_ms: 100,
+        }
+    }
+
+    /// Scale factor applied to every frame when it is written.
+    pub fn scale(mut self, factor: usize) -> Self {
+        assert!(factor > 0, "scale factor must be positive");
+        self.scale = factor;
+        self
+    }
+
+    /// Time between two frames of an animated GIF. GIFs store delays in steps of 10ms.
+    pub fn delay(mut self, delay_ms: u32) -> Self {
+        self.delay_ms = delay_ms;
+        self
+    }
+
+    /// Adds a frame. All frames of a recording must have the same size.
+    pub fn record(&mut self, frame: Image) {
+        if let Some(first) = self.frames.first() {
+            assert_eq!(
+                (first.width, first.height),
+                (frame.width, frame.height),
+                "all frames must have the same size"
+            );
+        }
+        self.frames.push(frame);
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// Saves every frame to `dir` as `frame-00000.{extension}`, `frame-00001.{extension}` and so
+    /// on, creating `dir` if it does not exist.
+    pub fn save_frames(&self, dir: impl AsRef<Path>, extension: &str) -> io::Result<()> {
+        let dir = dir.as_ref();
+        fs::create_dir_all(dir)?;
+
+        for (index, frame) in self.frames.iter().enumerate() {
+            let path = dir.join(format!("frame-{index:05}.{extension}"));
+            frame.scale(self.scale).save(path)?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes all frames as an animated GIF that loops forever.
+    ///
+    /// Frames that use at most 256 distinct colors in total are stored losslessly, otherwise
+    /// every frame is quantized on its own.
+    pub fn write_gif(&self, w: impl Write) -> io::Result<()> {
+        let Some(first) = self.frames.first() else {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "cannot write a GIF without frames",
+            ));
+        };
+
+        let size = |length: usize| {
+            u16::try_from(length * self.scale).map_err(|_| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "GIF frames are at most 65535 pixels wide",
+                )
+            })
+        };
+        let (width, height) = (size(first.width)?, size(first.height)?);
+        let delay = (self.delay_ms / 10).min(u16::MAX as u32) as u16;
+
+        let palette = self.palette();
+        let global_palette: Vec<u8> = match &palette {
+            Some(palette) => {
+                let mut colors = vec![[0; 3]; palette.len()];
+                palette
+                    .iter()
+                    .for_each(|(&color, &index)| colors[index as usize] = color);
+                colors.into_flattened()
+            }
+            None => Vec::new(),
+        };
+
+        let mut encoder =
+            gif::Encoder::new(w, width, height, &global_palette).map_err(io::Error::other)?;
+        encoder
+            .set_repeat(gif::Repeat::Infinite)
+            .map_err(io::Error::other)?;
+
+        for frame in &self.frames {
+            let frame = frame.scale(self.scale);
+            let mut gif_frame = match &palette {
+                Some(palette) => gif::Frame {
+                    width,
+                    height,
+                    buffer: Cow::Owned(frame.pixels.iter().map(|color| palette[color]).collect()),
+                    ..gif::Frame::default()
+                },
+                None => gif::Frame::from_rgb(width, height, frame.pixels.as_flattened()),
+            };
+            gif_frame.delay = delay;
+            encoder.write_frame(&gif_frame).map_err(io::Error::other)?;
+        }
+
+        Ok(())
+    }
+
+    /// Saves all frames as an animated GIF.
+    pub fn save_gif(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let mut file = BufWriter::new(File::create(path)?);
+        self.write_gif(&mut file)?;
+        file.flush()
+    }
+
+    /// Maps every color of the recording to a palette index, if there are at most 256 colors.
+    fn palette(&self) -> Option<HashMap<Rgb, u8>> {
+        let mut palette = HashMap::new();
+        for &color in self.frames.iter().flat_map(|frame| &frame.pixels) {
+            if !palette.contains_key(&color) {
+                let index = u8::try_from(palette.len()).ok()?;
+                palette.insert(color, index);
+            }
+        }
+        Some(palette)
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{FrameRecorder, Image};
+    use crate::util::grid::Grid;
+
+    fn image() -> Image {
+        let grid = Grid::parse("#.\n.#");
+        Image::from_grid(
+            &grid,
+            |&b| if b == b'#' { [255, 0, 0] } else { [0, 0, 255] },
+        )
+    }
+
+    #[test]
+    fn scales_pixels() {
+        let image = image().scale(3);
+        assert_eq!((image.width(), image.height()), (6, 6));
+        assert_eq!(image.pixel(2, 2), [255, 0, 0]);
+        assert_eq!(image.pixel(3, 2), [0, 0, 255]);
+        assert_eq!(image.pixel(5, 5), [255, 0, 0]);
+
+        let mut image = image.scale(1);
+        image.set_pixel(0, 5, [1, 2, 3]);
+        assert_eq!(image.pixel(0, 5), [1, 2, 3]);
+    }
+
+    #[test]
+    fn creates_images_of_grids_without_cells() {
+        let image = Image::from_grid(&Grid::from_fn(0, 3, |_| b'.'), |_| [0, 0, 0]);
+        assert_eq!((image.width(), image.height()), (0, 3));
+    }
+
+    #[test]
+    fn writes_ppm() {
+        let mut buffer = Vec::new();
+        image().write_ppm(&mut buffer).unwrap();
+        assert!(buffer.starts_with(b"P6\n2 2\n255\n"));
+        assert_eq!(buffer.len(), 11 + 2 * 2 * 3);
+    }
+
+    #[test]
+    fn writes_png() {
+        let mut buffer = Vec::new();
+        image().scale(4).write_png(&mut buffer).unwrap();
+
+        let decoder = png::Decoder::new(buffer.as_slice());
+        let reader = decoder.read_info().unwrap();
+        assert_eq!((reader.info().width, reader.info().height), (8, 8));
+    }
+
+    #[test]
+    fn writes_animated_gif() {
+        let mut recorder = FrameRecorder::new().scale(2);
+        assert!(recorder.write_gif(Vec::new()).is_err());
+
+        recorder.record(image());
+        recorder.record(image().scale(1));
+        assert_eq!(recorder.len(), 2);
+
+        let mut buffer = Vec::new();
+        recorder.write_gif(&mut buffer).unwrap();
+        assert!(buffer.starts_with(b"GIF89a"));
+
+        let mut decoder = gif::DecodeOptions::new()
+            .read_info(buffer.as_slice())
+            .unwrap();
+        let mut frames = 0;
+        while let Some(frame) = decoder.read_next_frame().unwrap() {
+            assert_eq!((frame.width, frame.height), (4, 4));
+            frames += 1;
+        }
+        assert_eq!(frames, 2);
+    }
+}
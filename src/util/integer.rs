@@ -0,0 +1,100 @@
+//! Common operations of the primitive integer types, so that helpers such as [`Point`] and the
+//! functions in [`math`] can be generic over the integer type.
+//!
+//! [`Point`]: crate::util::point::Point
+//! [`math`]: crate::util::math
+
+use std::fmt::Debug;
+use std::hash::{Hash, Hasher};
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Rem, Sub, SubAssign};
+
+pub trait Integer:
+    Copy
+    + Debug
+    + Default
+    + Ord
+    + Hash
+    + Add<Output = Self>
+    + AddAssign
+    + Sub<Output = Self>
+    + SubAssign
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+    const TEN: Self;
+
+    /// The absolute value, which is the value itself for unsigned types.
+    fn abs(self) -> Self;
+    fn rem_euclid(self, rhs: Self) -> Self;
+    /// Feeds the bits of the value to `state` with a single fixed-size write, which is cheaper
+    /// than going through `Hash` for hashers that only override those writes.
+    fn write_hash<H: Hasher>(self, state: &mut H);
+}
+
+pub trait Signed: Integer + Neg<Output = Self> {
+    fn signum(self) -> Self;
+}
+
+macro_rules! integer {
+    ($t:ty, $abs:expr, $bits:ty, $write:ident) => {
+        impl Integer for $t {
+            const ZERO: $t = 0;
+            const ONE: $t = 1;
+            const TEN: $t = 10;
+
+            #[inline]
+            fn abs(self) -> $t {
+                $abs(self)
+            }
+
+            #[inline]
+            fn rem_euclid(self, rhs: $t) -> $t {
+                <$t>::rem_euclid(self, rhs)
+            }
+
+            #[inline]
+            fn write_hash<H: Hasher>(self, state: &mut H) {
+                state.$write(self as $bits);
+            }
+        }
+    };
+}
+
+macro_rules! unsigned {
+    ($($t:ty => $write:ident)*) => ($(
+        integer!($t, |value: $t| value, $t, $write);
+    )*)
+}
+
+macro_rules! signed {
+    ($($t:ty => $bits:ty, $write:ident)*) => ($(
+        integer!($t, <$t>::abs, $bits, $write);
+
+        impl Signed for $t {
+            #[inline]
+            fn signum(self) -> $t {
+                <$t>::signum(self)
+            }
+        }
+    )*)
+}
+
+unsigned!(
+    u8 => write_u8
+    u16 => write_u16
+    u32 => write_u32
+    u64 => write_u64
+    u128 => write_u128
+    usize => write_usize
+);
+signed!(
+    i8 => u8, write_u8
+    i16 => u16, write_u16
+    i32 => u32, write_u32
+    i64 => u64, write_u64
+    i128 => u128, write_u128
+    isize => usize, write_usize
+);
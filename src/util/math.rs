@@ -0,0 +1,262 @@
+//! Number theory and digit helpers that keep coming up in puzzles.
+//!
+//! [`gcd`] and [`lcm`] work with any primitive integer, the modular helpers build on
+//! [`extended_gcd`]. [`crt`] solves systems of congruences, like buses or orbits that have to
+//! line up, even if the moduli share factors:
+//!
+//! ```
+//!   # use advent_of_code::util::math::{crt, lcm, mod_inverse};
+//!
+//!   assert_eq!(lcm(4_u64, 6), 12);
+//!   assert_eq!(mod_inverse(3, 7), Some(5));
+//!   assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+//! ```
+//!
+//! The digit helpers work on the decimal digits of non-negative integers, without going
+//! through strings or floating point logarithms:
+//!
+//! ```
+//!   # use advent_of_code::util::math::{digit_count, digits, split_digits};
+//!
+//!   assert_eq!(digit_count(253_000_u64), 6);
+//!   assert_eq!(split_digits(253_000_u64, 3), (253, 0));
+//!   assert_eq!(digits(2024_u32), vec![2, 0, 2, 4]);
+//! ```
+
+use crate::util::integer::{Integer, Signed};
+
+/// Non-negative greatest common divisor, `gcd(0, 0)` is 0.
+pub fn gcd<T: Integer>(a: T, b: T) -> T {
+    let (mut a, mut b) = (a.abs(), b.abs());
+    while b != T::ZERO {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Non-negative least common multiple, which is 0 if either number is 0.
+pub fn lcm<T: Integer>(a: T, b: T) -> T {
+    if a == T::ZERO || b == T::ZERO {
+        return T::ZERO;
+    }
+    (a / gcd(a, b) * b).abs()
+}
+
+/// Least common multiple of all numbers, 1 for no numbers.
+pub fn lcm_all<T: Integer>(numbers: impl IntoIterator<Item = T>) -> T {
+    numbers.into_iter().fold(T::ONE, lcm)
+}
+
+/// Returns `(g, x, y)` such that `a * x + b * y = g`, where `g` is the non-negative greatest
+/// common divisor of `a` and `b`.
+pub fn extended_gcd<T: Signed>(a: T, b: T) -> (T, T, T) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (T::ONE, T::ZERO);
+    let (mut old_y, mut y) = (T::ZERO, T::ONE);
+
+    while r != T::ZERO {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+
+    if old_r < T::ZERO {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// The inverse of `a` modulo `modulus` in `0..modulus`, or `None` if `a` and `modulus` are not
+/// coprime.
+pub fn mod_inverse<T: Signed>(a: T, modulus: T) -> Option<T> {
+    let (g, x, _) = extended_gcd(a.rem_euclid(modulus), modulus);
+    (g == T::ONE).then(|| x.rem_euclid(modulus))
+}
+
+/// `base` to the power of `exp` modulo `modulus`, by squaring. Intermediate products are
+/// computed with 128 bits, so any `u64` modulus works.
+pub fn mod_pow(base: u64, mut exp: u64, modulus: u64) -> u64 {
+    assert!(modulus > 0, "modulus must be positive");
+
+    let modulus = modulus as u128;
+    let mut base = base as u128 % modulus;
+    let mut result = 1 % modulus;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % modulus;
+        }
+        base = base * base % modulus;
+        exp >>= 1;
+    }
+    result as u64
+}
+
+/// Solves the system `x ≡ residue (mod modulus)` for all `(residue, modulus)` pairs with the
+/// chinese remainder theorem. Returns the smallest non-negative solution and the least common
+/// multiple of the moduli, after which the solutions repeat.
+///
+/// The moduli do not need to be coprime. Returns `None` if the congruences contradict each
+/// other, or if the least common multiple does not fit into an `i64`.
+pub fn crt(congruences: &[(i64, i64)]) -> Option<(i64, i64)> {
+    let (mut x, mut m) = (0_i128, 1_i128);
+
+    for &(residue, modulus) in congruences {
+        assert!(modulus > 0, "moduli must be positive");
+        let (residue, modulus) = (
+            (residue as i128).rem_euclid(modulus as i128),
+            modulus as i128,
+        );
+
+        // x + m * k ≡ residue (mod modulus), solved for k.
+        let (g, inverse, _) = extended_gcd(m, modulus);
+        if (residue - x) % g != 0 {
+            return None;
+        }
+
+        let step = modulus / g;
+        let k = ((residue - x) / g % step * inverse).rem_euclid(step);
+        x += m * k;
+        m *= step;
+        if m > i64::MAX as i128 {
+            return None;
+        }
+    }
+
+    Some((x as i64, m as i64))
+}
+
+/// Floor of the square root of a non-negative integer.
+pub fn isqrt<T: Integer>(n: T) -> T {
+    assert!(n >= T::ZERO, "square root of a negative number");
+
+    let two = T::ONE + T::ONE;
+    if n < two {
+        return n;
+    }
+
+    // Newton's method, starting above the root so that the estimates decrease.
+    let mut x = n / two + T::ONE;
+    let mut y = (x + n / x) / two;
+    while y < x {
+        x = y;
+        y = (x + n / x) / two;
+    }
+    x
+}
+
+/// `10` to the power of `exp`.
+pub fn pow10<T: Integer>(exp: u32) -> T {
+    (0..exp).fold(T::ONE, |power, _| power * T::TEN)
+}
+
+/// Number of decimal digits of a non-negative integer, where 0 has one digit.
+pub fn digit_count<T: Integer>(mut n: T) -> u32 {
+    let mut count = 1;
+    while n >= T::TEN {
+        n = n / T::TEN;
+        count += 1;
+    }
+    count
+}
+
+/// Decimal digits of a non-negative integer, most significant first.
+pub fn digits<T: Integer>(mut n: T) -> Vec<T> {
+    let mut digits = vec![n % T::TEN];
+    while n >= T::TEN {
+        n = n / T::TEN;
+        digits.push(n % T::TEN);
+    }
+    digits.reverse();
+    digits
+}
+
+/// Combines decimal digits, most significant first, into a number.
+pub fn from_digits<T: Integer>(digits: impl IntoIterator<Item = T>) -> T {
+    digits
+        .into_iter()
+        .fold(T::ZERO, |number, digit| number * T::TEN + digit)
+}
+
+/// Splits a non-negative integer into the digits before and the last `right` digits.
+pub fn split_digits<T: Integer>(n: T, right: u32) -> (T, T) {
+    let divisor = pow10::<T>(right);
+    (n / divisor, n % divisor)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn computes_gcd_and_lcm() {
+        assert_eq!(gcd(12, -18), 6);
+        assert_eq!(gcd(0_u8, 0), 0);
+        assert_eq!(gcd(0_i64, -5), 5);
+        assert_eq!(lcm(-4, 6), 12);
+        assert_eq!(lcm(0_u32, 6), 0);
+        assert_eq!(lcm_all([2_u64, 3, 4, 5]), 60);
+        assert_eq!(lcm_all(Vec::<u64>::new()), 1);
+    }
+
+    #[test]
+    fn solves_bezout_identity() {
+        for (a, b) in [(240, 46), (-7, 3), (0, 5), (5, 0), (-12, -18)] {
+            let (g, x, y) = extended_gcd(a, b);
+            assert_eq!(g, gcd(a, b));
+            assert_eq!(a * x + b * y, g);
+        }
+    }
+
+    #[test]
+    fn computes_modular_inverse_and_power() {
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(-3, 11), Some(7));
+        assert_eq!(mod_inverse(6, 9), None);
+
+        assert_eq!(mod_pow(4, 13, 497), 445);
+        assert_eq!(mod_pow(2, 0, 1), 0);
+        assert_eq!(mod_pow(u64::MAX, 2, u64::MAX - 1), 1);
+    }
+
+    #[test]
+    fn solves_congruences() {
+        assert_eq!(crt(&[]), Some((0, 1)));
+        assert_eq!(crt(&[(-1, 4)]), Some((3, 4)));
+        // moduli sharing a factor.
+        assert_eq!(crt(&[(1, 4), (3, 6)]), Some((9, 12)));
+        assert_eq!(crt(&[(1, 4), (2, 6)]), None);
+        // buses with ids 17, 13 and 19 that leave at offsets 0, 2 and 3.
+        assert_eq!(crt(&[(0, 17), (-2, 13), (-3, 19)]), Some((3417, 4199)));
+        assert_eq!(crt(&[(0, i64::MAX), (0, 2)]), None);
+    }
+
+    #[test]
+    fn computes_integer_square_roots() {
+        let roots: Vec<u32> = (0..10_u32).map(isqrt).collect();
+        assert_eq!(roots, vec![0, 1, 1, 1, 2, 2, 2, 2, 2, 3]);
+        assert_eq!(isqrt(u64::MAX), u32::MAX as u64);
+        assert_eq!(isqrt(i64::MAX), 3_037_000_499);
+        assert_eq!(isqrt(999_999_999_999_999_999_u64), 999_999_999);
+    }
+
+    #[test]
+    fn handles_digits() {
+        assert_eq!(digit_count(0_u64), 1);
+        assert_eq!(digit_count(999_999_999_999_999_u64), 15);
+        assert_eq!(digit_count(1_000_000_000_000_000_u64), 16);
+        assert_eq!(digit_count(u64::MAX), 20);
+
+        assert_eq!(digits(0_u8), vec![0]);
+        assert_eq!(digits(907_u32), vec![9, 0, 7]);
+        assert_eq!(from_digits([9_u32, 0, 7]), 907);
+
+        assert_eq!(pow10::<u64>(12), 1_000_000_000_000);
+        assert_eq!(split_digits(123_456_u64, 3), (123, 456));
+        assert_eq!(
+            split_digits(12_345_678_901_234_567_890_u64, 10),
+            (1_234_567_890, 1_234_567_890)
+        );
+    }
+}
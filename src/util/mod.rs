@@ -1,2 +1,12 @@
+pub mod differential;
+pub mod geometry;
 pub mod grid;
+pub mod image;
+pub mod integer;
+pub mod math;
+pub mod parse;
 pub mod point;
+pub mod render;
+pub mod rng;
+pub mod search;
+pub mod sparse_grid;
@@ -0,0 +1,366 @@
+//! Parsing helpers for common shapes of puzzle input, built on [`nom`].
+//!
+//! [`integers`] extracts every number of a line, ignoring whatever separates them. [`sections`]
+//! splits input at blank lines, and [`key_values`] parses lines like `3267: 81 40 27`:
+//!
+//! ```
+//!   # use advent_of_code::util::parse::{finish, integers, key_values, sections};
+//!
+//!   assert_eq!(integers::<i32>("p=0,4 v=3,-3").unwrap(), vec![0, 4, 3, -3]);
+//!
+//!   let [rules, updates] = sections("47|53\n97|13\n\n75,47").try_into().unwrap();
+//!   assert_eq!(rules.lines().count(), 2);
+//!   assert_eq!(updates, "75,47");
+//!
+//!   let line = "3267: 81 40 27";
+//!   let (value, numbers): (u64, Vec<u64>) = finish(line, key_values(line)).unwrap();
+//!   assert_eq!((value, numbers.len()), (3267, 3));
+//! ```
+//!
+//! [`finish`] turns the result of a parser into a [`miette::Report`] that points at the
+//! position in the input where parsing failed. Parsers that only see part of the input, like a
+//! single line, can [`relocate`] their reports so that they point into the whole input, and
+//! [`parse_lines`] does so for every line:
+//!
+//! ```
+//!   # use advent_of_code::util::parse::parse_lines;
+//!   # use miette::IntoDiagnostic;
+//!
+//!   let error = parse_lines("1\n2\nx", |line| line.parse::<u8>().into_diagnostic()).unwrap_err();
+//!   assert_eq!(error.labels().unwrap().next().unwrap().offset(), 4);
+//! ```
+
+use std::any::type_name;
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use miette::{miette, Diagnostic, LabeledSpan, Report, Severity, SourceCode};
+use nom::branch::alt;
+use nom::bytes::complete::{is_not, take_until};
+use nom::character::complete::{char, digit1, space0, space1};
+use nom::combinator::{map_res, opt, recognize};
+use nom::multi::separated_list1;
+use nom::sequence::{pair, separated_pair, terminated};
+use nom::IResult;
+
+/// Parses an integer with an optional leading `-`.
+pub fn integer<T: FromStr>(input: &str) -> IResult<&str, T> {
+    map_res(recognize(pair(opt(char('-')), digit1)), str::parse)(input)
+}
+
+/// Parses an integer without a sign.
+pub fn unsigned<T: FromStr>(input: &str) -> IResult<&str, T> {
+    map_res(digit1, str::parse)(input)
+}
+
+/// Extracts all integers from `input`, skipping any other characters. A `-` directly in front of
+/// a number makes it negative, so a range like `3-4` parses as `[3, -4]`; split such ranges at
+/// the `-` first. Returns a report pointing at the first number that does not fit into `T`,
+/// instead of silently dropping it and shifting all later numbers.
+pub fn integers<T: FromStr>(input: &str) -> miette::Result<Vec<T>> {
+    let mut numbers = Vec::new();
+    let mut rest = input;
+    while !rest.is_empty() {
+        match recognize(pair(opt(char('-')), digit1::<&str, ()>))(rest) {
+            Ok((next, number)) => {
+                let number = number.parse().map_err(|_| {
+                    let message = format!("`{number}` does not fit into {}", type_name::<T>());
+                    error_in(input, number, message)
+                })?;
+                numbers.push(number);
+                rest = next;
+            }
+            Err(_) => {
+                let skip = rest.chars().next().map_or(1, char::len_utf8);
+                rest = &rest[skip..];
+            }
+        }
+    }
+    Ok(numbers)
+}
+
+/// Splits `input` into sections separated by one or more blank lines. Lines that only contain
+/// whitespace count as blank, and the line break at the end of a section is not included.
+pub fn sections(input: &str) -> Vec<&str> {
+    let mut sections = Vec::new();
+    let mut start = None;
+    let mut end = 0;
+    let mut offset = 0;
+
+    for line in input.split_inclusive('\n') {
+        if line.trim().is_empty() {
+            if let Some(start) = start.take() {
+                sections.push(&input[start..end]);
+            }
+        } else {
+            start.get_or_insert(offset);
+            end = offset + line.trim_end_matches(['\r', '\n']).len();
+        }
+        offset += line.len();
+    }
+
+    if let Some(start) = start {
+        sections.push(&input[start..end]);
+    }
+    sections
+}
+
+/// Parses a line like `key: value value value`, where values are separated by whitespace or
+/// commas and both the key and the values are parsed with [`FromStr`].
+pub fn key_values<K: FromStr, V: FromStr>(input: &str) -> IResult<&str, (K, Vec<V>)> {
+    let key = map_res(take_until(":"), |key: &str| key.trim().parse());
+    let separator = alt((recognize(pair(char(','), space0)), space1));
+    let value = map_res(is_not(" \t,\r\n"), str::parse);
+
+    separated_pair(
+        key,
+        terminated(char(':'), space0),
+        separated_list1(separator, value),
+    )(input)
+}
+
+/// Converts the result of running a parser on `input` into a [`miette::Result`]. Fails if the
+/// parser failed, or if anything other than whitespace is left after parsing. The error points
+/// at the offending position of `input`.
+pub fn finish<T>(input: &str, result: IResult<&str, T>) -> miette::Result<T> {
+    let (rest, message) = match result {
+        Ok((rest, value)) if rest.trim().is_empty() => return Ok(value),
+        Ok((rest, _)) => (
+            rest.trim_start(),
+            "unexpected input after parsing".to_string(),
+        ),
+        Err(nom::Err::Error(error) | nom::Err::Failure(error)) => (
+            error.input,
+            format!("failed to parse input ({:?})", error.code),
+        ),
+        Err(nom::Err::Incomplete(_)) => ("", "incomplete input".to_string()),
+    };
+
+    let offset = input.len().saturating_sub(rest.len());
+    let length = rest.chars().next().map_or(0, char::len_utf8);
+    Err(error_at(input, offset, length, message))
+}
+
+/// Creates a report for `input` with a label at the given byte range.
+pub fn error_at(input: &str, offset: usize, length: usize, message: impl Into<String>) -> Report {
+    let message = message.into();
+    miette!(
+        labels = vec![LabeledSpan::at(offset..offset + length, "here")],
+        "{message}"
+    )
+    .with_source_code(input.to_string())
+}
+
+/// Creates a report for `input` that labels `part`, which must be a subslice of `input`.
+pub fn error_in(input: &str, part: &str, message: impl Into<String>) -> Report {
+    error_at(input, offset_in(input, part), part.len(), message)
+}
+
+/// Byte offset of `part` in `input`. Panics if `part` is not a subslice of `input`, like one of
+/// its lines or sections.
+pub fn offset_in(input: &str, part: &str) -> usize {
+    let offset = (part.as_ptr() as usize).wrapping_sub(input.as_ptr() as usize);
+    assert!(
+        offset <= input.len() && part.len() <= input.len() - offset,
+        "part is not a subslice of the input"
+    );
+    offset
+}
+
+/// Byte offset of a zero based `line` and `column` of `input`, clamped to the end of the input.
+pub fn offset_of(input: &str, line: usize, column: usize) -> usize {
+    let start: usize = input.split_inclusive('\n').take(line).map(str::len).sum();
+    (start + column).min(input.len())
+}
+
+/// Moves the labels of a report about `part` of `input` to the same position in `input`, and
+/// attaches `input` as source code. A report without labels gets a label spanning `part`.
+///
+/// Everything else about the report, like its help, code and chain of sources, is kept.
+pub fn relocate(report: Report, input: &str, part: &str) -> Report {
+    let offset = offset_in(input, part);
+    let labels: Vec<_> = match report.labels() {
+        Some(labels) => labels
+            .map(|label| {
+                LabeledSpan::new(
+                    label.label().map(str::to_string),
+                    offset + label.offset(),
+                    label.len(),
+                )
+            })
+            .collect(),
+        None => vec![LabeledSpan::at(offset..offset + part.len(), "here")],
+    };
+
+    Report::new(Relocated {
+        report,
+        labels,
+        source_code: input.to_string(),
+    })
+}
+
+/// A report with its labels moved into a larger input, see [`relocate`].
+#[derive(Debug)]
+struct Relocated {
+    report: Report,
+    labels: Vec<LabeledSpan>,
+    source_code: String,
+}
+
+impl Display for Relocated {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        Display::fmt(&*self.report, f)
+    }
+}
+
+impl Error for Relocated {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.report.source()
+    }
+}
+
+impl Diagnostic for Relocated {
+    fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.report.code()
+    }
+
+    fn severity(&self) -> Option<Severity> {
+        self.report.severity()
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.report.help()
+    }
+
+    fn url<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.report.url()
+    }
+
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        Some(&self.source_code)
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        Some(Box::new(self.labels.iter().cloned()))
+    }
+
+    fn related<'a>(&'a self) -> Option<Box<dyn Iterator<Item = &'a dyn Diagnostic> + 'a>> {
+        self.report.related()
+    }
+
+    fn diagnostic_source(&self) -> Option<&dyn Diagnostic> {
+        self.report.diagnostic_source()
+    }
+}
+
+/// Parses every non-empty line of `input` with `f`. Errors point at the offending line, or at
+/// the position within it if the error is a report with labels.
+pub fn parse_lines<T, E: Into<Report>>(
+    input: &str,
+    mut f: impl FnMut(&str) -> Result<T, E>,
+) -> miette::Result<Vec<T>> {
+    input
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| f(line).map_err(|error| relocate(error.into(), input, line)))
+        .collect()
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::*;
+    use miette::IntoDiagnostic;
+
+    #[test]
+    fn extracts_integers() {
+        assert_eq!(integers::<i64>("x=-12, y=7..-3").unwrap(), vec![-12, 7, -3]);
+        assert_eq!(integers::<i32>("3-4").unwrap(), vec![3, -4]);
+        assert_eq!(
+            integers::<i32>("no numbers - here").unwrap(),
+            Vec::<i32>::new()
+        );
+        assert_eq!(integers::<i32>("ünïcode 5").unwrap(), vec![5]);
+
+        let error = integers::<u8>("1 300 2").unwrap_err();
+        assert_eq!(error.to_string(), "`300` does not fit into u8");
+        let label = error.labels().unwrap().next().unwrap();
+        assert_eq!((label.offset(), label.len()), (2, 3));
+        assert!(integers::<u32>("1-3").is_err());
+        assert_eq!(integer::<i32>("-5 rest"), Ok((" rest", -5)));
+        assert!(unsigned::<u32>("-5").is_err());
+    }
+
+    #[test]
+    fn splits_sections() {
+        assert_eq!(sections("a\nb\n\nc\n"), vec!["a\nb", "c"]);
+        assert_eq!(sections("\n\na\r\n  \r\n\n\nb"), vec!["a", "b"]);
+        assert_eq!(sections(""), Vec::<&str>::new());
+    }
+
+    #[test]
+    fn parses_key_values() {
+        let line = "190: 10 19";
+        assert_eq!(key_values::<u64, u64>(line), Ok(("", (190, vec![10, 19]))));
+
+        let line = "a: 1, 2,3";
+        assert_eq!(
+            key_values::<String, u8>(line),
+            Ok(("", ("a".to_string(), vec![1, 2, 3])))
+        );
+    }
+
+    #[test]
+    fn reports_position_of_errors() {
+        let line = "190: 10 x9";
+        let error = finish(line, key_values::<u64, u64>(line)).unwrap_err();
+        let label = error.labels().unwrap().next().unwrap();
+        assert_eq!(label.offset(), 8);
+        assert!(error.source_code().is_some());
+
+        let error = finish("abc", unsigned::<u8>("abc")).unwrap_err();
+        assert_eq!(error.labels().unwrap().next().unwrap().offset(), 0);
+
+        assert_eq!(finish("7 \n", unsigned::<u8>("7 \n")).unwrap(), 7);
+    }
+
+    #[test]
+    fn points_into_the_whole_input() {
+        let input = "ab\ncde\nf";
+        let line = input.lines().nth(1).unwrap();
+        assert_eq!(offset_in(input, line), 3);
+        assert_eq!(offset_of(input, 1, 2), 5);
+        assert_eq!(offset_of(input, 5, 0), input.len());
+
+        let report = relocate(error_at(line, 1, 2, "bad"), input, line);
+        let label = report.labels().unwrap().next().unwrap();
+        assert_eq!((label.offset(), label.len()), (4, 2));
+        assert_eq!(report.to_string(), "bad");
+
+        let report = miette!(help = "use fewer letters", code = "too_long", "long line");
+        let report = relocate(report, input, line);
+        assert_eq!(report.to_string(), "long line");
+        assert_eq!(report.help().unwrap().to_string(), "use fewer letters");
+        assert_eq!(report.code().unwrap().to_string(), "too_long");
+        assert_eq!(report.labels().unwrap().next().unwrap().offset(), 3);
+
+        let report = relocate(miette!("digit").wrap_err("bad number"), input, line);
+        let chain: Vec<_> = report.chain().map(ToString::to_string).collect();
+        assert_eq!(chain, vec!["bad number", "digit"]);
+
+        let report = error_in(input, line, "bad line");
+        let label = report.labels().unwrap().next().unwrap();
+        assert_eq!((label.offset(), label.len()), (3, 3));
+    }
+
+    #[test]
+    fn parses_lines() {
+        let numbers = parse_lines("1\n\n-2\n", |line| line.parse::<i32>().into_diagnostic());
+        assert_eq!(numbers.unwrap(), vec![1, -2]);
+
+        let error = parse_lines("1\n300", |line| line.parse::<u8>().into_diagnostic()).unwrap_err();
+        assert!(error.to_string().contains("too large"));
+        let label = error.labels().unwrap().next().unwrap();
+        assert_eq!((label.offset(), label.len()), (2, 3));
+    }
+}
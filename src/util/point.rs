@@ -1,7 +1,30 @@
-use std::{
-    hash::{Hash, Hasher},
-    ops::{Add, AddAssign, Mul, Sub, SubAssign},
-};
+//! 2 and 3 dimensional points, generic over the signed integer type of their coordinates.
+//!
+//! [`Point`] and [`Point3`] default to `i32` coordinates, wider types can be used when
+//! coordinates would overflow:
+//!
+//! ```
+//!   # use advent_of_code::util::point::{Point, Point3, ORTHOGONAL_3D};
+//!
+//!   let far: Point<i64> = Point::new(3_000_000_000, 0);
+//!   assert_eq!(far.manhattan_distance(&Point::new(0, -1)), 3_000_000_001);
+//!
+//!   let cube = Point3::new(1, 2, 3);
+//!   assert_eq!(ORTHOGONAL_3D.map(|d| cube + d)[0], Point3::new(1, 1, 3));
+//! ```
+//!
+//! Points use screen coordinates, where `y` grows downwards: [`UP`] is `(0, -1)`, and turning
+//! it [clockwise](Point::clockwise) gives [`RIGHT`].
+
+use std::cmp::Ordering;
+use std::error::Error;
+use std::fmt::{self, Debug, Display};
+use std::hash::{Hash, Hasher};
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Rem, Sub, SubAssign};
+use std::str::FromStr;
+
+use crate::util::integer::Signed;
+use crate::util::math;
 
 pub const ORIGIN: Point = Point::new(0, 0);
 pub const UP: Point = Point::new(0, -1);
@@ -21,67 +44,184 @@ pub const DIAGONAL: [Point; 8] = [
     Point::new(1, 1),
 ];
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
-pub struct Point {
-    pub x: i32,
-    pub y: i32,
+pub const ORIGIN_3D: Point3 = Point3::new(0, 0, 0);
+/// The 6 neighbors sharing a face, along -y, +y, -x, +x, -z and +z.
+pub const ORTHOGONAL_3D: [Point3; 6] = [
+    Point3::new(0, -1, 0),
+    Point3::new(0, 1, 0),
+    Point3::new(-1, 0, 0),
+    Point3::new(1, 0, 0),
+    Point3::new(0, 0, -1),
+    Point3::new(0, 0, 1),
+];
+/// The 26 neighbors sharing a face, edge or corner, ordered by z, then y, then x.
+pub const DIAGONAL_3D: [Point3; 26] = {
+    let mut neighbors = [ORIGIN_3D; 26];
+    let mut i = 0;
+    let mut z = -1;
+    while z <= 1 {
+        let mut y = -1;
+        while y <= 1 {
+            let mut x = -1;
+            while x <= 1 {
+                if x != 0 || y != 0 || z != 0 {
+                    neighbors[i] = Point3::new(x, y, z);
+                    i += 1;
+                }
+                x += 1;
+            }
+            y += 1;
+        }
+        z += 1;
+    }
+    neighbors
+};
+
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Point<T = i32> {
+    pub x: T,
+    pub y: T,
 }
 
-impl Point {
+impl<T> Point<T> {
     #[inline]
     #[must_use]
-    pub const fn new(x: i32, y: i32) -> Self {
+    pub const fn new(x: T, y: T) -> Self {
         Self { x, y }
     }
+}
 
+impl<T: Signed> Point<T> {
+    /// Rotates by 90° clockwise on screen, where `y` grows downwards like in [`Direction`], so
+    /// `(x, y)` becomes `(-y, x)` and [`UP`] becomes [`RIGHT`].
     pub fn clockwise(&self) -> Self {
-        Point::new(self.y, -self.x)
+        Point::new(-self.y, self.x)
     }
 
+    /// Rotates by 90° counter-clockwise on screen, see [`Point::clockwise`].
     pub fn counter_clockwise(&self) -> Self {
-        Point::new(-self.y, self.x)
+        Point::new(self.y, -self.x)
     }
 
-    pub fn manhattan_distance(&self, other: &Self) -> i32 {
+    pub fn manhattan_distance(&self, other: &Self) -> T {
         (self.x - other.x).abs() + (self.y - other.y).abs()
     }
 
+    /// Distance when moving diagonally costs the same as moving orthogonally, like a king in
+    /// chess.
+    pub fn chebyshev_distance(&self, other: &Self) -> T {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    /// Square of the Euclidean distance, which is exact and orders points like the distance.
+    pub fn squared_distance(&self, other: &Self) -> T {
+        let (dx, dy) = (self.x - other.x, self.y - other.y);
+        dx * dx + dy * dy
+    }
+
     pub fn signum(self, other: Self) -> Self {
         Point::new((self.x - other.x).signum(), (self.y - other.y).signum())
     }
+
+    /// Divides both coordinates by their greatest common divisor, giving the shortest step that
+    /// hits every lattice point on the line through the origin and `self`.
+    pub fn reduce(self) -> Self {
+        let divisor = math::gcd(self.x, self.y);
+        if divisor == T::ZERO {
+            self
+        } else {
+            Point::new(self.x / divisor, self.y / divisor)
+        }
+    }
+}
+
+/// Parses the step of length 1 in a direction, see [`Direction`].
+impl TryFrom<u8> for Point {
+    type Error = ParseDirectionError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Direction::try_from(value).map(Point::from)
+    }
+}
+
+/// Orders points in reading order, top to bottom and then left to right.
+impl<T: Ord> Ord for Point<T> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        (&self.y, &self.x).cmp(&(&other.y, &other.x))
+    }
 }
 
-impl Hash for Point {
+impl<T: Ord> PartialOrd for Point<T> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Writes the coordinates directly, which is noticeably faster than the derived impl for the
+/// hash sets and maps of points used in most grid puzzles.
+impl<T: Signed> Hash for Point<T> {
     #[inline]
     fn hash<H: Hasher>(&self, state: &mut H) {
-        state.write_u32(self.x as u32);
-        state.write_u32(self.y as u32);
+        self.x.write_hash(state);
+        self.y.write_hash(state);
     }
 }
 
-impl From<u8> for Point {
-    fn from(value: u8) -> Self {
-        match value {
-            b'^' => UP,
-            b'v' => DOWN,
-            b'<' => LEFT,
-            b'>' => RIGHT,
-            _ => unreachable!(),
+/// Formats a point as `x,y`, the way most puzzle inputs list coordinates.
+impl<T: Display> Display for Point<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+/// Parses `x,y` or `(x, y)`, ignoring whitespace around the coordinates.
+impl<T: FromStr> FromStr for Point<T> {
+    type Err = ParsePointError<T::Err>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let s = s
+            .strip_prefix('(')
+            .and_then(|s| s.strip_suffix(')'))
+            .unwrap_or(s);
+        let (x, y) = s.split_once(',').ok_or(ParsePointError::Format)?;
+        let coordinate = |c: &str| c.trim().parse().map_err(ParsePointError::Coordinate);
+        Ok(Point::new(coordinate(x)?, coordinate(y)?))
+    }
+}
+
+/// Reasons why a string could not be parsed into a [`Point`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParsePointError<E> {
+    /// The string is not of the form `x,y` or `(x, y)`.
+    Format,
+    /// A coordinate is not a valid number.
+    Coordinate(E),
+}
+
+impl<E: Display> Display for ParsePointError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParsePointError::Format => write!(f, "expected a point like `x,y` or `(x, y)`"),
+            ParsePointError::Coordinate(error) => write!(f, "invalid coordinate: {error}"),
         }
     }
 }
 
-impl Add for Point {
+impl<E: Debug + Display> Error for ParsePointError<E> {}
+
+impl<T: Signed> Add for Point<T> {
     type Output = Self;
 
     #[inline]
-    #[must_use]
     fn add(self, rhs: Self) -> Self {
         Point::new(self.x + rhs.x, self.y + rhs.y)
     }
 }
 
-impl AddAssign for Point {
+impl<T: Signed> AddAssign for Point<T> {
     #[inline]
     fn add_assign(&mut self, rhs: Self) {
         self.x += rhs.x;
@@ -89,30 +229,461 @@ impl AddAssign for Point {
     }
 }
 
-impl Mul<i32> for Point {
+impl<T: Signed> Mul<T> for Point<T> {
     type Output = Self;
 
     #[inline]
-    #[must_use]
-    fn mul(self, rhs: i32) -> Self {
+    fn mul(self, rhs: T) -> Self {
         Point::new(self.x * rhs, self.y * rhs)
     }
 }
 
-impl Sub for Point {
+impl<T: Signed> Neg for Point<T> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+/// Divides both coordinates, rounding towards zero.
+impl<T: Signed> Div<T> for Point<T> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: T) -> Self {
+        Point::new(self.x / rhs, self.y / rhs)
+    }
+}
+
+/// Euclidean remainder of each coordinate, which is never negative. Wraps a point into the
+/// rectangle from the origin to `rhs`, e.g. `point % Point::new(width, height)`.
+impl<T: Signed> Rem for Point<T> {
+    type Output = Self;
+
+    #[inline]
+    fn rem(self, rhs: Self) -> Self {
+        Point::new(self.x.rem_euclid(rhs.x), self.y.rem_euclid(rhs.y))
+    }
+}
+
+impl<T: Signed> Sub for Point<T> {
     type Output = Self;
 
     #[inline]
-    #[must_use]
     fn sub(self, rhs: Self) -> Self {
         Point::new(self.x - rhs.x, self.y - rhs.y)
     }
 }
 
-impl SubAssign for Point {
+impl<T: Signed> SubAssign for Point<T> {
     #[inline]
     fn sub_assign(&mut self, rhs: Self) {
         self.x -= rhs.x;
         self.y -= rhs.y;
     }
 }
+
+/// One of the 4 orthogonal directions, with north pointing up towards negative `y`.
+///
+/// Directions are indexed clockwise starting at north, so that state can be stored in arrays of
+/// length 4. They parse from arrows `^>v<`, compass points `NESW` and `UDLR`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    /// All directions in clockwise order, so that `ALL[d.index()] == d`.
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    #[inline]
+    pub const fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Returns the direction with the given index, wrapping around after 4.
+    #[inline]
+    pub const fn from_index(index: usize) -> Self {
+        Direction::ALL[index % 4]
+    }
+
+    #[inline]
+    pub const fn clockwise(self) -> Self {
+        Direction::from_index(self.index() + 1)
+    }
+
+    #[inline]
+    pub const fn counter_clockwise(self) -> Self {
+        Direction::from_index(self.index() + 3)
+    }
+
+    #[inline]
+    pub const fn opposite(self) -> Self {
+        Direction::from_index(self.index() + 2)
+    }
+
+    /// The step of length 1 in this direction.
+    #[inline]
+    pub const fn to_point(self) -> Point {
+        match self {
+            Direction::North => UP,
+            Direction::East => RIGHT,
+            Direction::South => DOWN,
+            Direction::West => LEFT,
+        }
+    }
+
+    /// The arrow pointing in this direction, one of `^>v<`.
+    #[inline]
+    pub const fn arrow(self) -> char {
+        match self {
+            Direction::North => '^',
+            Direction::East => '>',
+            Direction::South => 'v',
+            Direction::West => '<',
+        }
+    }
+}
+
+impl From<Direction> for Point {
+    #[inline]
+    fn from(direction: Direction) -> Self {
+        direction.to_point()
+    }
+}
+
+impl TryFrom<u8> for Direction {
+    type Error = ParseDirectionError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            b'^' | b'N' | b'U' => Ok(Direction::North),
+            b'>' | b'E' | b'R' => Ok(Direction::East),
+            b'v' | b'S' | b'D' => Ok(Direction::South),
+            b'<' | b'W' | b'L' => Ok(Direction::West),
+            _ => Err(ParseDirectionError(value)),
+        }
+    }
+}
+
+impl FromStr for Direction {
+    type Err = ParseDirectionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.as_bytes() {
+            &[value] => Direction::try_from(value),
+            _ => Err(ParseDirectionError(s.bytes().next().unwrap_or(b' '))),
+        }
+    }
+}
+
+/// A byte that is not one of `^>v<`, `NESW` or `UDLR`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseDirectionError(pub u8);
+
+impl Display for ParseDirectionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "invalid direction {:?}, expected one of ^>v<, NESW or UDLR",
+            self.0 as char
+        )
+    }
+}
+
+impl Error for ParseDirectionError {}
+
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Point3<T = i32> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Point3<T> {
+    #[inline]
+    #[must_use]
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl<T: Signed> Point3<T> {
+    pub fn manhattan_distance(&self, other: &Self) -> T {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+
+    pub fn chebyshev_distance(&self, other: &Self) -> T {
+        (self.x - other.x)
+            .abs()
+            .max((self.y - other.y).abs())
+            .max((self.z - other.z).abs())
+    }
+
+    pub fn squared_distance(&self, other: &Self) -> T {
+        let (dx, dy, dz) = (self.x - other.x, self.y - other.y, self.z - other.z);
+        dx * dx + dy * dy + dz * dz
+    }
+
+    pub fn signum(self, other: Self) -> Self {
+        Point3::new(
+            (self.x - other.x).signum(),
+            (self.y - other.y).signum(),
+            (self.z - other.z).signum(),
+        )
+    }
+}
+
+impl<T: Signed> Hash for Point3<T> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.x.write_hash(state);
+        self.y.write_hash(state);
+        self.z.write_hash(state);
+    }
+}
+
+impl<T: Signed> Add for Point3<T> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Point3::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl<T: Signed> AddAssign for Point3<T> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+        self.z += rhs.z;
+    }
+}
+
+impl<T: Signed> Mul<T> for Point3<T> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: T) -> Self {
+        Point3::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+impl<T: Signed> Sub for Point3<T> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Point3::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl<T: Signed> SubAssign for Point3<T> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+        self.z -= rhs.z;
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::collections::{BTreeSet, HashSet};
+    use std::hash::{Hash, Hasher};
+
+    use super::{
+        Direction, ParseDirectionError, ParsePointError, Point, Point3, DIAGONAL_3D, DOWN, LEFT,
+        ORIGIN_3D, ORTHOGONAL_3D, RIGHT, UP,
+    };
+
+    #[test]
+    fn supports_wide_coordinates() {
+        let mut point: Point<i64> = Point::new(i32::MAX as i64, 0);
+        point += Point::new(1, -1) * 2;
+        assert_eq!(point, Point::new(i32::MAX as i64 + 2, -2));
+        assert_eq!(point.manhattan_distance(&Point::new(0, 0)), 2_147_483_651);
+        assert_eq!(Point::new(2_i8, 3).clockwise(), Point::new(-3, 2));
+        assert_eq!(RIGHT - UP, Point::new(1, 1));
+    }
+
+    #[test]
+    fn measures_distances() {
+        let (a, b) = (Point::new(1, -2), Point::new(4, 2));
+        assert_eq!(a.manhattan_distance(&b), 7);
+        assert_eq!(a.chebyshev_distance(&b), 4);
+        assert_eq!(a.squared_distance(&b), 25);
+
+        let (a, b) = (Point3::new(0, 0, 0), Point3::new(1, -2, 2));
+        assert_eq!(a.chebyshev_distance(&b), 2);
+        assert_eq!(a.squared_distance(&b), 9);
+    }
+
+    #[test]
+    fn reduces_steps() {
+        assert_eq!(Point::new(6, -4).reduce(), Point::new(3, -2));
+        assert_eq!(Point::new(0, -5).reduce(), Point::new(0, -1));
+        assert_eq!(Point::new(-7, 0).reduce(), Point::new(-1, 0));
+        assert_eq!(Point::new(0, 0).reduce(), Point::new(0, 0));
+    }
+
+    #[test]
+    fn does_3d_arithmetic() {
+        let a = Point3::new(1, -2, 3);
+        let b = Point3::new(-1, 2, 0);
+        assert_eq!(a + b, Point3::new(0, 0, 3));
+        assert_eq!(a - b, Point3::new(2, -4, 3));
+        assert_eq!(a * 2, Point3::new(2, -4, 6));
+        assert_eq!(a.manhattan_distance(&b), 9);
+        assert_eq!(a.signum(b), Point3::new(1, -1, 1));
+
+        let mut c = a;
+        c -= a;
+        assert_eq!(c, ORIGIN_3D);
+    }
+
+    #[test]
+    fn has_3d_neighborhoods() {
+        let neighbors: HashSet<Point3> = DIAGONAL_3D.into_iter().collect();
+        assert_eq!(neighbors.len(), 26);
+        assert!(!neighbors.contains(&ORIGIN_3D));
+        assert!(ORTHOGONAL_3D.iter().all(|n| neighbors.contains(n)));
+        assert!(ORTHOGONAL_3D
+            .iter()
+            .all(|n| n.manhattan_distance(&ORIGIN_3D) == 1));
+    }
+
+    #[test]
+    fn hashes_coordinates_directly() {
+        /// Records fixed-size writes and rejects byte slices, which the fast hash never writes.
+        #[derive(Default)]
+        struct WriteRecorder(Vec<u64>);
+
+        impl Hasher for WriteRecorder {
+            fn finish(&self) -> u64 {
+                0
+            }
+
+            fn write(&mut self, _: &[u8]) {
+                panic!("points should hash their coordinates with fixed-size writes");
+            }
+
+            fn write_u32(&mut self, i: u32) {
+                self.0.push(i as u64);
+            }
+
+            fn write_u64(&mut self, i: u64) {
+                self.0.push(i);
+            }
+        }
+
+        let mut hasher = WriteRecorder::default();
+        Point::new(-1, 2).hash(&mut hasher);
+        assert_eq!(hasher.0, vec![u32::MAX as u64, 2]);
+
+        let mut hasher = WriteRecorder::default();
+        Point3::new(1_i64, 2, -1).hash(&mut hasher);
+        assert_eq!(hasher.0, vec![1, 2, u64::MAX]);
+    }
+
+    #[test]
+    fn rotates_in_screen_coordinates() {
+        // y grows downwards, so a clockwise turn goes up, right, down, left.
+        assert_eq!(UP, Point::new(0, -1));
+        assert_eq!(UP.clockwise(), RIGHT);
+        assert_eq!(RIGHT.clockwise(), DOWN);
+        assert_eq!(DOWN.clockwise(), LEFT);
+        assert_eq!(LEFT.clockwise(), UP);
+        assert_eq!(UP.counter_clockwise(), LEFT);
+        assert_eq!(Point::new(2_i8, 3).counter_clockwise(), Point::new(3, -2));
+    }
+
+    #[test]
+    fn turns_directions() {
+        for direction in Direction::ALL {
+            assert_eq!(Direction::ALL[direction.index()], direction);
+            assert_eq!(direction.clockwise().counter_clockwise(), direction);
+            assert_eq!(direction.opposite().opposite(), direction);
+            assert_eq!(
+                direction.clockwise().to_point(),
+                direction.to_point().clockwise()
+            );
+            assert_eq!(
+                direction.counter_clockwise().to_point(),
+                direction.to_point().counter_clockwise()
+            );
+        }
+        assert_eq!(Direction::West.clockwise(), Direction::North);
+        assert_eq!(Direction::North.counter_clockwise(), Direction::West);
+        assert_eq!(Direction::East.opposite(), Direction::West);
+        assert_eq!(Point::from(Direction::North), UP);
+    }
+
+    #[test]
+    fn parses_directions() {
+        for (arrows, direction) in ["^NU", ">ER", "vSD", "<WL"].iter().zip(Direction::ALL) {
+            for b in arrows.bytes() {
+                assert_eq!(Direction::try_from(b), Ok(direction));
+            }
+            assert_eq!(arrows[..1].parse(), Ok(direction));
+            assert_eq!(direction.arrow(), arrows.chars().next().unwrap());
+        }
+        assert_eq!(Point::try_from(b'<'), Ok(LEFT));
+        assert_eq!(Direction::try_from(b'x'), Err(ParseDirectionError(b'x')));
+        assert!("^^".parse::<Direction>().is_err());
+        assert!("".parse::<Direction>().is_err());
+    }
+
+    #[test]
+    fn orders_points_in_reading_order() {
+        let points: BTreeSet<Point> = [Point::new(2, 1), Point::new(5, 0), Point::new(-1, 1)]
+            .into_iter()
+            .collect();
+        assert_eq!(
+            points.into_iter().collect::<Vec<_>>(),
+            vec![Point::new(5, 0), Point::new(-1, 1), Point::new(2, 1)]
+        );
+        assert!(UP < LEFT);
+    }
+
+    #[test]
+    fn parses_and_formats_points() {
+        assert_eq!("3,-4".parse(), Ok(Point::new(3, -4)));
+        assert_eq!(" (12, 7) ".parse(), Ok(Point::new(12, 7)));
+        assert_eq!(
+            "8000000000,1".parse(),
+            Ok(Point::<i64>::new(8_000_000_000, 1))
+        );
+        assert_eq!("3;4".parse::<Point>(), Err(ParsePointError::Format));
+        assert!(matches!(
+            "3,x".parse::<Point>(),
+            Err(ParsePointError::Coordinate(_))
+        ));
+
+        let point = Point::new(-2, 9);
+        assert_eq!(point.to_string(), "-2,9");
+        assert_eq!(point.to_string().parse(), Ok(point));
+    }
+
+    #[test]
+    fn negates_divides_and_wraps() {
+        assert_eq!(-Point::new(3, -1), Point::new(-3, 1));
+        assert_eq!(-UP, Point::new(0, 1));
+        assert_eq!(Point::new(7, -7) / 2, Point::new(3, -3));
+
+        let size = Point::new(11, 7);
+        assert_eq!(Point::new(-1, -8) % size, Point::new(10, 6));
+        assert_eq!(Point::new(24, 7) % size, Point::new(2, 0));
+    }
+}
@@ -0,0 +1,271 @@
+//! Renders a [`Grid`] of any cell type as text, for inspecting the state of a solution.
+//!
+//! Cells are mapped to characters with a closure. Overlays are drawn on top of the cells:
+//! replaced characters ([`mark`]), a [`path`] drawn with arrows and a [`cursor`]. With ANSI
+//! colors enabled, points can additionally be [highlighted] in a color.
+//!
+//! ```
+//!   # use advent_of_code::util::grid::Grid;
+//!   # use advent_of_code::util::point::Point;
+//!
+//!   let grid = Grid::parse("...\n...");
+//!   let output = grid
+//!       .renderer(|&b| b as char)
+//!       .path([Point::new(0, 0), Point::new(1, 0), Point::new(1, 1), Point::new(2, 1)])
+//!       .cursor(Point::new(2, 1), '@')
+//!       .to_string();
+//!   assert_eq!(output, ">v.\n.>@\n");
+//! ```
+//!
+//! [`Grid`]: crate::util::grid::Grid
+//! [`mark`]: Renderer::mark
+//! [`path`]: Renderer::path
+//! [`cursor`]: Renderer::cursor
+//! [highlighted]: Renderer::highlight
+
+use std::collections::HashMap;
+use std::fmt::{self, Display, Write};
+use std::io;
+
+use crate::util::grid::Grid;
+use crate::util::point::{Point, DOWN, LEFT, RIGHT, UP};
+
+const ANSI_BOLD: &str = "\x1b[1m";
+const ANSI_RESET: &str = "\x1b[0m";
+
+/// Foreground colors for highlighted cells.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Color {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+}
+
+impl Color {
+    fn ansi(self) -> &'static str {
+        match self {
+            Color::Red => "\x1b[31m",
+            Color::Green => "\x1b[32m",
+            Color::Yellow => "\x1b[33m",
+            Color::Blue => "\x1b[34m",
+            Color::Magenta => "\x1b[35m",
+            Color::Cyan => "\x1b[36m",
+        }
+    }
+}
+
+/// Overlay of a single cell. Later overlays take precedence over earlier ones.
+#[derive(Clone, Copy, Default)]
+struct Overlay {
+    char: Option<char>,
+    color: Option<Color>,
+    bold: bool,
+}
+
+/// Builder for rendering a grid, created with [`Grid::renderer`].
+pub struct Renderer<'a, T, F> {
+    grid: &'a Grid<T>,
+    to_char: F,
+    overlays: HashMap<Point, Overlay>,
+    ansi: bool,
+    rulers: bool,
+}
+
+impl<T> Grid<T> {
+    /// Starts rendering the grid, mapping every cell to a character with `to_char`.
+    pub fn renderer<F: Fn(&T) -> char>(&self, to_char: F) -> Renderer<'_, T, F> {
+        Renderer {
+            grid: self,
+            to_char,
+            overlays: HashMap::new(),
+            ansi: false,
+            rulers: false,
+        }
+    }
+}
+
+impl<T, F: Fn(&T) -> char> Renderer<'_, T, F> {
+    /// Colors the given points. Colors are only written when ANSI output is enabled.
+    pub fn highlight(mut self, points: impl IntoIterator<Item = Point>, color: Color) -> Self {
+        for point in points {
+            self.overlays.entry(point).or_default().color = Some(color);
+        }
+        self
+    }
+
+    /// Draws `char` instead of the cells at the given points.
+    pub fn mark(mut self, points: impl IntoIterator<Item = Point>, char: char) -> Self {
+        for point in points {
+            self.overlays.entry(point).or_default().char = Some(char);
+        }
+        self
+    }
+
+    /// Draws a path of adjacent points with arrows pointing to the next point of the path. The
+    /// last point is left as is, so that it can be marked with a [`cursor`](Self::cursor).
+    pub fn path(mut self, points: impl IntoIterator<Item = Point>) -> Self {
+        let points: Vec<Point> = points.into_iter().collect();
+        for step in points.windows(2) {
+            let arrow = match step[1] - step[0] {
+                UP => '^',
+                DOWN => 'v',
+                LEFT => '<',
+                RIGHT => '>',
+                _ => '*',
+            };
+            let overlay = self.overlays.entry(step[0]).or_default();
+            overlay.char = Some(arrow);
+            overlay.color = Some(Color::Yellow);
+        }
+        self
+    }
+
+    /// Draws `char` in bold at `point`, e.g. the position of a robot.
+    pub fn cursor(mut self, point: Point, char: char) -> Self {
+        let overlay = self.overlays.entry(point).or_default();
+        overlay.char = Some(char);
+        overlay.bold = true;
+        self
+    }
+
+    /// Enables ANSI escape codes for colors and bold text.
+    pub fn ansi(mut self, enabled: bool) -> Self {
+        self.ansi = enabled;
+        self
+    }
+
+    /// Adds column numbers above and row numbers left of the grid.
+    pub fn rulers(mut self, enabled: bool) -> Self {
+        self.rulers = enabled;
+        self
+    }
+
+    /// Writes the rendered grid to a [`fmt::Write`], e.g. a `String` or a `Formatter`.
+    pub fn write_to(&self, f: &mut impl Write) -> fmt::Result {
+        let (width, height) = (self.grid.width(), self.grid.height());
+        let label_width = (height - 1).max(0).to_string().len();
+
+        if self.rulers {
+            // one ruler line per digit of the widest column number, most significant first.
+            let digits = (width - 1).max(0).to_string().len() as u32;
+            for digit in (0..digits).rev() {
+                write!(f, "{:label_width$} ", "")?;
+                for x in 0..width {
+                    let value = x / 10_i32.pow(digit);
+                    if value == 0 && digit > 0 {
+                        f.write_char(' ')?;
+                    } else {
+                        write!(f, "{}", value % 10)?;
+                    }
+                }
+                writeln!(f)?;
+            }
+        }
+
+        for (y, row) in self.grid.rows().enumerate() {
+            if self.rulers {
+                write!(f, "{y:>label_width$} ")?;
+            }
+
+            for (x, cell) in row.iter().enumerate() {
+                let point = Point::new(x as i32, y as i32);
+                let overlay = self.overlays.get(&point).copied().unwrap_or_default();
+                let char = overlay.char.unwrap_or_else(|| (self.to_char)(cell));
+
+                let styled = self.ansi && (overlay.color.is_some() || overlay.bold);
+                if styled {
+                    if overlay.bold {
+                        f.write_str(ANSI_BOLD)?;
+                    }
+                    if let Some(color) = overlay.color {
+                        f.write_str(color.ansi())?;
+                    }
+                }
+                f.write_char(char)?;
+                if styled {
+                    f.write_str(ANSI_RESET)?;
+                }
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes the rendered grid to an [`io::Write`], e.g. stdout or a file.
+    pub fn write_io(&self, w: &mut impl io::Write) -> io::Result<()> {
+        w.write_all(self.to_string().as_bytes())
+    }
+
+    pub fn print(&self) {
+        print!("{self}");
+    }
+}
+
+impl<T, F: Fn(&T) -> char> Display for Renderer<'_, T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.write_to(f)
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Color;
+    use crate::util::grid::Grid;
+    use crate::util::point::Point;
+
+    #[test]
+    fn renders_cells_with_closure() {
+        let grid = Grid::parse_with("01\n10", |b| Ok::<_, ()>(b == b'1')).unwrap();
+        let output = grid
+            .renderer(|&set| if set { '#' } else { '.' })
+            .to_string();
+        assert_eq!(output, ".#\n#.\n");
+    }
+
+    #[test]
+    fn draws_overlays_in_order() {
+        let grid = Grid::parse("....\n....");
+        let output = grid
+            .renderer(|&b| b as char)
+            .mark([Point::new(3, 0), Point::new(0, 1)], 'x')
+            .path([Point::new(0, 0), Point::new(0, 1), Point::new(1, 1)])
+            .cursor(Point::new(3, 0), '@')
+            .to_string();
+        assert_eq!(output, "v..@\n>...\n");
+    }
+
+    #[test]
+    fn writes_ansi_colors() {
+        let grid = Grid::parse("ab");
+        let output = grid
+            .renderer(|&b| b as char)
+            .highlight([Point::new(1, 0)], Color::Red)
+            .ansi(true)
+            .to_string();
+        assert_eq!(output, "a\x1b[31mb\x1b[0m\n");
+
+        let plain = grid
+            .renderer(|&b| b as char)
+            .highlight([Point::new(1, 0)], Color::Red)
+            .to_string();
+        assert_eq!(plain, "ab\n");
+    }
+
+    #[test]
+    fn draws_rulers() {
+        let grid = Grid::new(12, 2, b'.');
+        let output = grid.renderer(|&b| b as char).rulers(true).to_string();
+        assert_eq!(
+            output,
+            "            11\n  012345678901\n0 ............\n1 ............\n"
+        );
+
+        let mut buffer = Vec::new();
+        grid.renderer(|&b| b as char).write_io(&mut buffer).unwrap();
+        assert_eq!(buffer.len(), 2 * 13);
+    }
+}
@@ -0,0 +1,571 @@
+//! Shortest path searches over arbitrary states.
+//!
+//! States can be anything hashable, e.g. a [`Point`] or a `(Point, direction)` tuple. The graph is
+//! given implicitly by a closure that returns the neighbors of a state, which makes it easy to
+//! search a [`Grid`] directly:
+//!
+//! ```
+//!   # use advent_of_code::util::grid::Grid;
+//!   # use advent_of_code::util::search::bfs;
+//!
+//!   let grid = Grid::parse("S.#\n..#\n#.E");
+//!   let start = grid.find(b'S').unwrap();
+//!   let end = grid.find(b'E').unwrap();
+//!
+//!   let search = bfs(
+//!       start,
+//!       |&point| grid.orthogonal_neighbors(point).filter(|&next| grid[next] != b'#'),
+//!       |&point| point == end,
+//!   );
+//!   assert_eq!(search.distance(&end), Some(4));
+//!   assert_eq!(search.path_to(&end).unwrap().len(), 5);
+//! ```
+//!
+//! Every search stops as soon as a state satisfying `is_goal` is taken from the queue, pass
+//! `|_| false` to explore all reachable states. Weighted searches return the lowest cost to each
+//! visited state. [`dijkstra_buckets`] replaces the binary heap with a bucket queue, which is
+//! faster when edge costs are small integers.
+//!
+//! The `_all` variants of the searches record every predecessor on a shortest path instead of
+//! only the first one, and keep going until all goals at the lowest goal distance are found. The
+//! resulting predecessor graph can be used to [mark every state] on any shortest path, or to
+//! [count] the distinct shortest paths. Costs need to be positive for these variants.
+//!
+//! [`Point`]: crate::util::point::Point
+//! [`Grid`]: crate::util::grid::Grid
+//! [mark every state]: Search::on_shortest_paths
+//! [count]: Search::count_paths
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+/// Distances and predecessors of all states visited by a search.
+#[derive(Clone, Debug)]
+pub struct Search<S> {
+    distances: HashMap<S, usize>,
+    predecessors: HashMap<S, Vec<S>>,
+    goals: Vec<S>,
+    all_predecessors: bool,
+}
+
+/// What to do with a state that was taken from the queue.
+enum Visit {
+    Expand,
+    Skip,
+    Stop,
+}
+
+impl<S: Clone + Eq + Hash> Search<S> {
+    fn new(start: S, all_predecessors: bool) -> Self {
+        Search {
+            distances: HashMap::from([(start, 0)]),
+            predecessors: HashMap::new(),
+            goals: Vec::new(),
+            all_predecessors,
+        }
+    }
+
+    /// Lowest known cost from the start to `state`, or `None` if it was not reached.
+    #[inline]
+    pub fn distance(&self, state: &S) -> Option<usize> {
+        self.distances.get(state).copied()
+    }
+
+    /// Lowest known costs of all reached states.
+    #[inline]
+    pub fn distances(&self) -> &HashMap<S, usize> {
+        &self.distances
+    }
+
+    /// The state that precedes `state` on a shortest path from the start.
+    #[inline]
+    pub fn predecessor(&self, state: &S) -> Option<&S> {
+        self.predecessors.get(state)?.first()
+    }
+
+    /// All states that precede `state` on a shortest path from the start. Only contains more
+    /// than one state for the `_all` variants of the searches.
+    #[inline]
+    pub fn predecessors(&self, state: &S) -> &[S] {
+        self.predecessors.get(state).map_or(&[], Vec::as_slice)
+    }
+
+    /// The goal state that ended the search, if any.
+    #[inline]
+    pub fn goal(&self) -> Option<&S> {
+        self.goals.first()
+    }
+
+    /// All goal states found at the lowest goal distance. Only contains more than one state for
+    /// the `_all` variants of the searches.
+    #[inline]
+    pub fn goals(&self) -> &[S] {
+        &self.goals
+    }
+
+    /// Distance to the goal state that ended the search, if any.
+    #[inline]
+    pub fn goal_distance(&self) -> Option<usize> {
+        self.distance(self.goals.first()?)
+    }
+
+    /// Reconstructs a shortest path from the start to `state`, including both ends.
+    pub fn path_to(&self, state: &S) -> Option<Vec<S>> {
+        self.distances.get(state)?;
+
+        let mut path = vec![state.clone()];
+        while let Some(previous) = self.predecessor(path.last()?) {
+            path.push(previous.clone());
+        }
+
+        path.reverse();
+        Some(path)
+    }
+
+    /// Returns every state that lies on any shortest path from the start to one of `targets`,
+    /// including the start and the reached targets.
+    pub fn on_shortest_paths(&self, targets: impl IntoIterator<Item = S>) -> HashSet<S> {
+        let mut todo: Vec<S> = targets
+            .into_iter()
+            .filter(|target| self.distances.contains_key(target))
+            .collect();
+        let mut seen: HashSet<S> = todo.iter().cloned().collect();
+
+        while let Some(state) = todo.pop() {
+            for previous in self.predecessors(&state) {
+                if seen.insert(previous.clone()) {
+                    todo.push(previous.clone());
+                }
+            }
+        }
+
+        seen
+    }
+
+    /// Counts the distinct shortest paths from the start to any of `targets`.
+    pub fn count_paths(&self, targets: impl IntoIterator<Item = S>) -> u64 {
+        let targets: HashSet<S> = targets.into_iter().collect();
+
+        // predecessors are always closer to the start, so counting in order of distance sees
+        // every predecessor before the states it leads to.
+        let mut states: Vec<S> = self
+            .on_shortest_paths(targets.iter().cloned())
+            .into_iter()
+            .collect();
+        states.sort_unstable_by_key(|state| self.distances[state]);
+
+        let mut counts: HashMap<S, u64> = HashMap::with_capacity(states.len());
+        for state in states {
+            let previous = self.predecessors(&state);
+            let count = if previous.is_empty() {
+                1
+            } else {
+                previous.iter().map(|p| counts[p]).sum()
+            };
+            counts.insert(state, count);
+        }
+
+        targets.iter().filter_map(|target| counts.get(target)).sum()
+    }
+
+    /// Records `cost` for `next` if it improves on the known cost. Returns `true` on improvement.
+    fn relax(&mut self, state: &S, next: &S, cost: usize) -> bool {
+        match self.distances.get(next) {
+            Some(&known) if known < cost => false,
+            Some(&known) if known == cost => {
+                if self.all_predecessors {
+                    self.predecessors
+                        .entry(next.clone())
+                        .or_default()
+                        .push(state.clone());
+                }
+                false
+            }
+            _ => {
+                self.distances.insert(next.clone(), cost);
+                self.predecessors.insert(next.clone(), vec![state.clone()]);
+                true
+            }
+        }
+    }
+
+    fn visit(&mut self, state: &S, cost: usize, is_goal: &mut impl FnMut(&S) -> bool) -> Visit {
+        if self.goal_distance().is_some_and(|lowest| cost > lowest) {
+            return Visit::Stop;
+        }
+
+        if !is_goal(state) {
+            return Visit::Expand;
+        }
+
+        self.goals.push(state.clone());
+        if self.all_predecessors {
+            Visit::Skip
+        } else {
+            Visit::Stop
+        }
+    }
+}
+
+/// Breadth first search where every step costs 1.
+pub fn bfs<S, I>(
+    start: S,
+    neighbors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Search<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    breadth_first(Search::new(start.clone(), false), start, neighbors, is_goal)
+}
+
+/// Like [`bfs`], but records all predecessors on shortest paths and all goals at the lowest
+/// goal distance.
+pub fn bfs_all<S, I>(
+    start: S,
+    neighbors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Search<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    breadth_first(Search::new(start.clone(), true), start, neighbors, is_goal)
+}
+
+fn breadth_first<S, I>(
+    mut search: Search<S>,
+    start: S,
+    mut neighbors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut queue = VecDeque::from([(start, 0)]);
+
+    while let Some((state, cost)) = queue.pop_front() {
+        match search.visit(&state, cost, &mut is_goal) {
+            Visit::Stop => break,
+            Visit::Skip => continue,
+            Visit::Expand => {}
+        }
+
+        for next in neighbors(&state) {
+            if search.relax(&state, &next, cost + 1) {
+                queue.push_back((next, cost + 1));
+            }
+        }
+    }
+
+    search
+}
+
+/// Dijkstra's algorithm using a binary heap. `neighbors` returns `(state, cost)` pairs.
+pub fn dijkstra<S, I>(
+    start: S,
+    neighbors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Search<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    best_first(
+        Search::new(start.clone(), false),
+        start,
+        neighbors,
+        |_| 0,
+        is_goal,
+    )
+}
+
+/// Like [`dijkstra`], but records all predecessors on shortest paths and all goals at the
+/// lowest goal distance.
+pub fn dijkstra_all<S, I>(
+    start: S,
+    neighbors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Search<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    best_first(
+        Search::new(start.clone(), true),
+        start,
+        neighbors,
+        |_| 0,
+        is_goal,
+    )
+}
+
+/// A* search. `heuristic` estimates the remaining cost to a goal and must never overestimate it,
+/// e.g. the manhattan distance on a grid where every step costs at least 1.
+pub fn astar<S, I>(
+    start: S,
+    neighbors: impl FnMut(&S) -> I,
+    heuristic: impl FnMut(&S) -> usize,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Search<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    best_first(
+        Search::new(start.clone(), false),
+        start,
+        neighbors,
+        heuristic,
+        is_goal,
+    )
+}
+
+fn best_first<S, I>(
+    mut search: Search<S>,
+    start: S,
+    mut neighbors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> usize,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    // the heap holds indices into `states`, so that states do not need to implement `Ord`.
+    let mut heap = BinaryHeap::from([Reverse((heuristic(&start), 0, 0))]);
+    let mut states = vec![start];
+
+    while let Some(Reverse((_, cost, index))) = heap.pop() {
+        let state = states[index].clone();
+
+        // skip stale entries that have been improved since they were queued.
+        if search.distances[&state] < cost {
+            continue;
+        }
+
+        match search.visit(&state, cost, &mut is_goal) {
+            Visit::Stop => break,
+            Visit::Skip => continue,
+            Visit::Expand => {}
+        }
+
+        for (next, step) in neighbors(&state) {
+            let next_cost = cost + step;
+            if search.relax(&state, &next, next_cost) {
+                heap.push(Reverse((
+                    next_cost + heuristic(&next),
+                    next_cost,
+                    states.len(),
+                )));
+                states.push(next);
+            }
+        }
+    }
+
+    search
+}
+
+/// Dijkstra's algorithm using a bucket queue. Faster than [`dijkstra`] when costs are small
+/// integers, as the queue is a ring of `max_cost + 1` buckets indexed by cost. `neighbors` must
+/// not return costs above `max_cost`.
+pub fn dijkstra_buckets<S, I>(
+    start: S,
+    max_cost: usize,
+    neighbors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Search<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    bucket_first(
+        Search::new(start.clone(), false),
+        start,
+        max_cost,
+        neighbors,
+        is_goal,
+    )
+}
+
+/// Like [`dijkstra_buckets`], but records all predecessors on shortest paths and all goals at
+/// the lowest goal distance.
+pub fn dijkstra_buckets_all<S, I>(
+    start: S,
+    max_cost: usize,
+    neighbors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Search<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    bucket_first(
+        Search::new(start.clone(), true),
+        start,
+        max_cost,
+        neighbors,
+        is_goal,
+    )
+}
+
+fn bucket_first<S, I>(
+    mut search: Search<S>,
+    start: S,
+    max_cost: usize,
+    mut neighbors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    let len = max_cost + 1;
+    let mut buckets = vec![Vec::new(); len];
+    buckets[0].push(start);
+    let mut pending = 1;
+    let mut cost = 0;
+
+    while pending > 0 {
+        let index = cost % len;
+
+        while let Some(state) = buckets[index].pop() {
+            pending -= 1;
+
+            // skip stale entries that have been improved since they were queued.
+            if search.distances[&state] != cost {
+                continue;
+            }
+
+            match search.visit(&state, cost, &mut is_goal) {
+                Visit::Stop => return search,
+                Visit::Skip => continue,
+                Visit::Expand => {}
+            }
+
+            for (next, step) in neighbors(&state) {
+                assert!(
+                    step <= max_cost,
+                    "cost {step} exceeds maximum of {max_cost}"
+                );
+
+                let next_cost = cost + step;
+                if search.relax(&state, &next, next_cost) {
+                    buckets[next_cost % len].push(next);
+                    pending += 1;
+                }
+            }
+        }
+
+        cost += 1;
+    }
+
+    search
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{
+        astar, bfs, bfs_all, dijkstra, dijkstra_all, dijkstra_buckets, dijkstra_buckets_all,
+    };
+    use crate::util::grid::Grid;
+    use crate::util::point::Point;
+
+    const MAZE: &str = "S..#\n.#.#\n.#..\n...E";
+
+    fn open_neighbors(grid: &Grid<u8>, point: Point) -> impl Iterator<Item = Point> + '_ {
+        grid.orthogonal_neighbors(point)
+            .filter(|&next| grid[next] != b'#')
+    }
+
+    #[test]
+    fn finds_shortest_path_with_bfs() {
+        let grid = Grid::parse(MAZE);
+        let (start, end) = (grid.find(b'S').unwrap(), grid.find(b'E').unwrap());
+
+        let search = bfs(start, |&p| open_neighbors(&grid, p), |&p| p == end);
+        assert_eq!(search.goal(), Some(&end));
+        assert_eq!(search.goal_distance(), Some(6));
+
+        let path = search.path_to(&end).unwrap();
+        assert_eq!(path.len(), 7);
+        assert_eq!(path.first(), Some(&start));
+        assert!(path.windows(2).all(|w| w[0].manhattan_distance(&w[1]) == 1));
+    }
+
+    #[test]
+    fn explores_everything_without_goal() {
+        let grid = Grid::parse(MAZE);
+        let search = bfs(Point::new(0, 0), |&p| open_neighbors(&grid, p), |_| false);
+        assert_eq!(search.goal(), None);
+        assert_eq!(search.distances().len(), 12);
+        assert_eq!(search.distance(&Point::new(3, 0)), None);
+    }
+
+    #[test]
+    fn weighted_searches_agree() {
+        // entering a cell costs its digit.
+        let grid = Grid::parse("1911\n1919\n1119\n9111");
+        let start = Point::new(0, 0);
+        let end = Point::new(3, 3);
+        let neighbors = |&p: &Point| {
+            grid.orthogonal_neighbors(p)
+                .map(|next| (next, (grid[next] - b'0') as usize))
+                .collect::<Vec<_>>()
+        };
+
+        let heap = dijkstra(start, neighbors, |&p| p == end);
+        let buckets = dijkstra_buckets(start, 9, neighbors, |&p| p == end);
+        let guided = astar(
+            start,
+            neighbors,
+            |p| p.manhattan_distance(&end) as usize,
+            |&p| p == end,
+        );
+
+        assert_eq!(heap.goal_distance(), Some(6));
+        assert_eq!(buckets.goal_distance(), Some(6));
+        assert_eq!(guided.goal_distance(), Some(6));
+        for search in [heap, buckets, guided] {
+            let path = search.path_to(&end).unwrap();
+            let cost: usize = path[1..].iter().map(|&p| (grid[p] - b'0') as usize).sum();
+            assert_eq!(cost, 6);
+        }
+    }
+
+    #[test]
+    fn counts_and_marks_all_shortest_paths() {
+        // shortest paths pass the wall on either side, 4 of them in total.
+        let grid = Grid::parse("S...\n.#..\n...E");
+        let (start, end) = (grid.find(b'S').unwrap(), grid.find(b'E').unwrap());
+
+        let search = bfs_all(start, |&p| open_neighbors(&grid, p), |&p| p == end);
+        assert_eq!(search.goal_distance(), Some(5));
+        assert_eq!(search.count_paths([end]), 4);
+        assert_eq!(search.on_shortest_paths([end]).len(), 11);
+
+        // without recording all predecessors, only a single path is known.
+        let single = bfs(start, |&p| open_neighbors(&grid, p), |&p| p == end);
+        assert_eq!(single.count_paths([end]), 1);
+        assert_eq!(single.on_shortest_paths([end]).len(), 6);
+    }
+
+    #[test]
+    fn finds_all_goals_at_lowest_distance() {
+        // states are a position on a line and a flag, both goals are reached at cost 2.
+        let neighbors = |&(x, flag): &(i32, bool)| [((x + 1, flag), 1), ((x + 1, !flag), 1)];
+        let is_goal = |&(x, _): &(i32, bool)| x == 2;
+
+        for search in [
+            dijkstra_all((0, false), neighbors, is_goal),
+            dijkstra_buckets_all((0, false), 1, neighbors, is_goal),
+        ] {
+            assert_eq!(search.goals().len(), 2);
+            assert_eq!(search.goal_distance(), Some(2));
+            assert_eq!(search.count_paths(search.goals().to_vec()), 4);
+        }
+    }
+}
@@ -0,0 +1,208 @@
+//! Unbounded 2 dimensional grid backed by a `HashMap`, for puzzles where coordinates grow over
+//! time or become negative. Cells that were never set read as a default value.
+//!
+//! ```
+//!   # use advent_of_code::util::point::Point;
+//!   # use advent_of_code::util::sparse_grid::SparseGrid;
+//!
+//!   let mut grid = SparseGrid::new(b'.');
+//!   grid[Point::new(-2, 1)] = b'#';
+//!   grid[Point::new(3, -1)] = b'#';
+//!
+//!   assert_eq!(grid[Point::new(0, 0)], b'.');
+//!   assert_eq!(grid.bounds(), Some((Point::new(-2, -1), Point::new(3, 1))));
+//!   assert_eq!(grid.render(|&b| b as char), ".....#\n......\n#.....\n");
+//! ```
+
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::ops::{Index, IndexMut};
+
+use crate::util::point::Point;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point, T>,
+    default: T,
+    bounds: Option<(Point, Point)>,
+}
+
+impl<T> SparseGrid<T> {
+    /// Creates an empty grid where every cell reads as `default`.
+    pub fn new(default: T) -> Self {
+        SparseGrid {
+            cells: HashMap::new(),
+            default,
+            bounds: None,
+        }
+    }
+
+    /// Returns the value at `point`, or the default if it was never set.
+    #[inline]
+    pub fn get(&self, point: Point) -> &T {
+        self.cells.get(&point).unwrap_or(&self.default)
+    }
+
+    /// Returns `true` if a value was set at `point`.
+    #[inline]
+    pub fn contains(&self, point: Point) -> bool {
+        self.cells.contains_key(&point)
+    }
+
+    /// Sets the value at `point`, returning the previous value if it was set.
+    pub fn insert(&mut self, point: Point, value: T) -> Option<T> {
+        self.extend_bounds(point);
+        self.cells.insert(point, value)
+    }
+
+    /// Resets `point` to the default, returning the value if it was set.
+    pub fn remove(&mut self, point: Point) -> Option<T> {
+        let value = self.cells.remove(&point)?;
+
+        // only points on the border of the bounding box can shrink it.
+        if let Some((min, max)) = self.bounds {
+            if point.x == min.x || point.x == max.x || point.y == min.y || point.y == max.y {
+                self.bounds = None;
+                let points: Vec<Point> = self.cells.keys().copied().collect();
+                points.into_iter().for_each(|p| self.extend_bounds(p));
+            }
+        }
+
+        Some(value)
+    }
+
+    /// Number of cells that were set.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Top left and bottom right corner of the smallest rectangle containing all set cells.
+    #[inline]
+    pub fn bounds(&self) -> Option<(Point, Point)> {
+        self.bounds
+    }
+
+    /// All set cells, in arbitrary order.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells.iter().map(|(&point, value)| (point, value))
+    }
+
+    /// Renders the bounding box row by row, mapping every cell to a character with `to_char`.
+    pub fn render(&self, to_char: impl Fn(&T) -> char) -> String {
+        let mut output = String::new();
+        let Some((min, max)) = self.bounds else {
+            return output;
+        };
+
+        for y in min.y..=max.y {
+            for x in min.x..=max.x {
+                output.push(to_char(self.get(Point::new(x, y))));
+            }
+            let _ = writeln!(output);
+        }
+
+        output
+    }
+
+    pub fn print(&self, to_char: impl Fn(&T) -> char) {
+        print!("{}", self.render(to_char));
+    }
+
+    fn extend_bounds(&mut self, point: Point) {
+        self.bounds = Some(match self.bounds {
+            None => (point, point),
+            Some((min, max)) => (
+                Point::new(min.x.min(point.x), min.y.min(point.y)),
+                Point::new(max.x.max(point.x), max.y.max(point.y)),
+            ),
+        });
+    }
+}
+
+impl<T: Default> Default for SparseGrid<T> {
+    fn default() -> Self {
+        SparseGrid::new(T::default())
+    }
+}
+
+impl<T: Default> FromIterator<(Point, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Point, T)>>(iter: I) -> Self {
+        let mut grid = SparseGrid::default();
+        for (point, value) in iter {
+            grid.insert(point, value);
+        }
+        grid
+    }
+}
+
+impl<T> Index<Point> for SparseGrid<T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, point: Point) -> &Self::Output {
+        self.get(point)
+    }
+}
+
+impl<T: Clone> IndexMut<Point> for SparseGrid<T> {
+    /// Returns the cell at `point`, setting it to the default first if it was not set.
+    #[inline]
+    fn index_mut(&mut self, point: Point) -> &mut Self::Output {
+        self.extend_bounds(point);
+        self.cells
+            .entry(point)
+            .or_insert_with(|| self.default.clone())
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::SparseGrid;
+    use crate::util::point::Point;
+
+    #[test]
+    fn reads_default_for_unset_cells() {
+        let mut grid = SparseGrid::new(0);
+        assert_eq!(grid[Point::new(-100, 100)], 0);
+        assert!(grid.is_empty());
+
+        grid[Point::new(1, 1)] += 5;
+        grid[Point::new(1, 1)] += 5;
+        assert_eq!(grid[Point::new(1, 1)], 10);
+        assert_eq!(grid.len(), 1);
+    }
+
+    #[test]
+    fn tracks_bounds() {
+        let mut grid: SparseGrid<u8> = SparseGrid::default();
+        assert_eq!(grid.bounds(), None);
+
+        grid.insert(Point::new(-3, 2), 1);
+        grid.insert(Point::new(4, -1), 1);
+        grid.insert(Point::new(0, 0), 1);
+        assert_eq!(grid.bounds(), Some((Point::new(-3, -1), Point::new(4, 2))));
+
+        assert_eq!(grid.remove(Point::new(-3, 2)), Some(1));
+        assert_eq!(grid.bounds(), Some((Point::new(0, -1), Point::new(4, 0))));
+
+        grid.remove(Point::new(4, -1));
+        grid.remove(Point::new(0, 0));
+        assert_eq!(grid.bounds(), None);
+    }
+
+    #[test]
+    fn renders_bounding_box() {
+        let grid: SparseGrid<bool> = [(Point::new(-1, -1), true), (Point::new(1, 0), true)]
+            .into_iter()
+            .collect();
+        let render = grid.render(|&set| if set { '#' } else { '.' });
+        assert_eq!(render, "#..\n..#\n");
+    }
+}